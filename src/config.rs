@@ -0,0 +1,126 @@
+use crate::error::{Error, Result};
+use crate::query::Parameters;
+use std::num::IntErrorKind;
+
+/// What `Query::from_http_with_config` and `Query::from_json_with_config` do
+/// with a `limit` or `offset` that is malformed or past its maximum
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Policy {
+    /// Reject it with `Error::InvalidLimit` / `Error::InvalidOffset`
+    Strict,
+    /// Use the default for a malformed value and clamp one past the maximum
+    #[default]
+    Lenient,
+}
+
+/// Bounds on the pagination `from_http` and `from_json` accept
+///
+/// The default config keeps `from_http`'s behaviour: no maximums, and a
+/// malformed value falls back to `Parameters::DEFAULT_LIMIT` or
+/// `Parameters::DEFAULT_OFFSET`.
+///
+/// # Examples
+/// - `ParseConfig::new().max_limit(100)` clamps `limit=5000` to 100
+/// - `ParseConfig::new().max_limit(100).policy(Policy::Strict)` rejects
+///   `limit=5000` and `limit=abc`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseConfig {
+    default_limit: usize,
+    max_limit: Option<usize>,
+    max_offset: Option<usize>,
+    policy: Policy,
+}
+
+impl ParseConfig {
+    pub fn new() -> Self {
+        Self {
+            default_limit: Parameters::DEFAULT_LIMIT,
+            max_limit: None,
+            max_offset: None,
+            policy: Policy::Lenient,
+        }
+    }
+
+    /// The limit of a query without `limit=`, capped at the maximum limit
+    pub fn default_limit(mut self, limit: usize) -> Self {
+        self.default_limit = limit;
+        self
+    }
+
+    pub fn max_limit(mut self, limit: usize) -> Self {
+        self.max_limit = Some(limit);
+        self
+    }
+
+    pub fn max_offset(mut self, offset: usize) -> Self {
+        self.max_offset = Some(offset);
+        self
+    }
+
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The limit a query gets when it does not ask for one
+    pub fn initial_limit(&self) -> usize {
+        self.max_limit
+            .map_or(self.default_limit, |max| self.default_limit.min(max))
+    }
+
+    /// Resolve a `limit=` value against the maximum and policy
+    pub fn parse_limit(&self, value: &str) -> Result<usize> {
+        self.resolve(value, self.initial_limit(), self.max_limit)
+            .ok_or_else(|| Error::InvalidLimit(value.into()))
+    }
+
+    /// Resolve an `offset=` value against the maximum and policy
+    pub fn parse_offset(&self, value: &str) -> Result<usize> {
+        self.resolve(value, Parameters::DEFAULT_OFFSET, self.max_offset)
+            .ok_or_else(|| Error::InvalidOffset(value.into()))
+    }
+
+    /// Resolve a 1-based page number into the offset of that page, against
+    /// the maximum offset and policy
+    ///
+    /// # Examples
+    /// - `ParseConfig::new().parse_page("3", 20)` -> `Ok(40)`
+    pub fn parse_page(&self, value: &str, limit: usize) -> Result<usize> {
+        let offset = parse_number(value, self.max_offset)
+            .and_then(|number| number.checked_sub(1))
+            .map(|page| page.saturating_mul(limit));
+        self.bound(offset, Parameters::DEFAULT_OFFSET, self.max_offset)
+            .ok_or_else(|| Error::InvalidOffset(value.into()))
+    }
+
+    /// `None` if the policy rejects the value
+    fn resolve(&self, value: &str, default: usize, max: Option<usize>) -> Option<usize> {
+        self.bound(parse_number(value, max), default, max)
+    }
+
+    /// Apply the maximum and policy to a parsed value, `None` if malformed
+    fn bound(&self, parsed: Option<usize>, default: usize, max: Option<usize>) -> Option<usize> {
+        match (parsed, max, self.policy) {
+            (Some(number), Some(max), Policy::Lenient) => Some(number.min(max)),
+            (Some(number), Some(max), Policy::Strict) => (number <= max).then_some(number),
+            (Some(number), None, _) => Some(number),
+            (None, _, Policy::Lenient) => Some(default),
+            (None, _, Policy::Strict) => None,
+        }
+    }
+}
+
+fn parse_number(value: &str, max: Option<usize>) -> Option<usize> {
+    match value.parse::<usize>() {
+        Ok(number) => Some(number),
+        // Too many digits for a usize: past any maximum, malformed otherwise
+        Err(error) if *error.kind() == IntErrorKind::PosOverflow => max.map(|_| usize::MAX),
+        Err(_) => None,
+    }
+}
+
+impl Default for ParseConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
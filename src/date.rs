@@ -0,0 +1,198 @@
+//! Date and time values in filters
+//!
+//! Values are resolved to seconds since the Unix epoch, in UTC. Accepted forms:
+//! - ISO-8601 dates and date-times: `2025-01-01`, `2025-01-01T10:30`,
+//!   `2025-01-01 10:30:00`, `2025-01-01T10:30:00.250Z`, `2025-01-01T10:30:00+02:00`
+//! - `now` or `today` (UTC midnight), optionally followed by offsets such as
+//!   `now-7d`, `today+1d` or `now-1h30m`, in `s`, `m`, `h`, `d` or `w` units
+
+pub(crate) const SECONDS_PER_DAY: i64 = 86_400;
+
+const NOW: &str = "now";
+const TODAY: &str = "today";
+
+/// Julian day number of the Unix epoch, 1970-01-01T00:00:00Z
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
+
+/// Resolve a date value to Unix seconds, with `now` as the current time
+pub(crate) fn parse(value: &str, now: i64) -> Option<i64> {
+    let value = value.trim();
+    if let Some(offsets) = value.strip_prefix(NOW) {
+        return relative(now, offsets);
+    }
+    if let Some(offsets) = value.strip_prefix(TODAY) {
+        return relative(now - now.rem_euclid(SECONDS_PER_DAY), offsets);
+    }
+    iso(value)
+}
+
+/// `YYYY-MM-DD`
+pub(crate) fn format_date(seconds: i64) -> String {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// `YYYY-MM-DD HH:MM:SS`, the format SQLite's `datetime()` produces
+pub(crate) fn format_date_time(seconds: i64) -> String {
+    let time = seconds.rem_euclid(SECONDS_PER_DAY);
+    format!(
+        "{} {:02}:{:02}:{:02}",
+        format_date(seconds),
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// Fractional days since noon UTC on November 24, 4714 BC, as `julianday()`
+pub(crate) fn julian_day(seconds: i64) -> f64 {
+    seconds as f64 / SECONDS_PER_DAY as f64 + UNIX_EPOCH_JULIAN_DAY
+}
+
+/// Apply offsets such as `-7d` or `+1h30m` to a base time
+fn relative(base: i64, offsets: &str) -> Option<i64> {
+    let mut seconds = base;
+    let mut rest = offsets;
+    while !rest.is_empty() {
+        let sign = match rest.as_bytes()[0] {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        rest = &rest[1..];
+
+        // One or more `<amount><unit>` pairs share the sign, e.g. `-1h30m`
+        let mut any = false;
+        while let Some(digits) = rest.find(|c: char| !c.is_ascii_digit()) {
+            if digits == 0 {
+                break;
+            }
+            let amount: i64 = rest[..digits].parse().ok()?;
+            let unit = match rest.as_bytes()[digits] {
+                b's' => 1,
+                b'm' => 60,
+                b'h' => 3600,
+                b'd' => SECONDS_PER_DAY,
+                b'w' => 7 * SECONDS_PER_DAY,
+                _ => return None,
+            };
+            seconds = seconds.checked_add(sign * amount.checked_mul(unit)?)?;
+            rest = &rest[digits + 1..];
+            any = true;
+        }
+        if !any {
+            return None;
+        }
+    }
+    Some(seconds)
+}
+
+/// Parse an ISO-8601 date or date-time
+fn iso(value: &str) -> Option<i64> {
+    if value.len() < 10 || !value.is_char_boundary(10) {
+        return None;
+    }
+    let (date, rest) = value.split_at(10);
+
+    let bytes = date.as_bytes();
+    if bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = digits(&date[0..4])?;
+    let month = digits(&date[5..7])?;
+    let day = digits(&date[8..10])?;
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    let days = days_from_civil(year, month, day);
+
+    if rest.is_empty() {
+        return Some(days * SECONDS_PER_DAY);
+    }
+    let time = rest.strip_prefix('T').or_else(|| rest.strip_prefix(' '))?;
+    Some(days * SECONDS_PER_DAY + time_of_day(time)?)
+}
+
+/// `HH:MM[:SS[.fff]]` followed by an optional `Z` or `±HH:MM` offset, as
+/// seconds since midnight UTC (possibly outside a single day)
+fn time_of_day(value: &str) -> Option<i64> {
+    let (time, offset) = match value.find(['Z', 'z', '+', '-']) {
+        Some(index) => value.split_at(index),
+        None => (value, ""),
+    };
+
+    // Fractions of a second are dropped
+    let time = time.split('.').next()?;
+    let mut parts = time.split(':');
+    let hour = digits(parts.next()?)?;
+    let minute = digits(parts.next()?)?;
+    let second = match parts.next() {
+        Some(second) => digits(second)?,
+        None => 0,
+    };
+    if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    let seconds = hour * 3600 + minute * 60 + second;
+
+    let offset = match offset {
+        "" | "Z" | "z" => 0,
+        _ => {
+            let sign = if offset.starts_with('-') { 1 } else { -1 };
+            let offset = offset[1..].replace(':', "");
+            // Byte slicing below needs single-byte characters
+            if offset.len() != 4 || !offset.is_ascii() {
+                return None;
+            }
+            let hours = digits(&offset[0..2])?;
+            let minutes = digits(&offset[2..4])?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+    Some(seconds + offset)
+}
+
+fn digits(value: &str) -> Option<i64> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date of a number of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
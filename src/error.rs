@@ -19,4 +19,39 @@ pub enum Error {
 
     #[error("Error: Invalid Search Parameters '{0}'")]
     InvalidSearchParameters(String),
+
+    #[error("Error: Invalid Filter '{0}'")]
+    InvalidFilter(String),
+
+    #[error("Error: Invalid JSON '{0}'")]
+    InvalidJson(String),
+
+    #[error("Error: Unknown Field '{0}'")]
+    UnknownField(String),
+
+    #[error("Error: Invalid Value '{1}' for Field '{0}'")]
+    InvalidValue(String, String),
+
+    #[error("Error: Invalid Limit '{0}'")]
+    InvalidLimit(String),
+
+    #[error("Error: Invalid Offset '{0}'")]
+    InvalidOffset(String),
+
+    #[error("Error: Invalid Cursor '{0}'")]
+    InvalidCursor(String),
+
+    #[error("Error: Missing Filter for Statement on '{0}'")]
+    MissingFilter(String),
+
+    #[error("Error: Missing Assignments for Update on '{0}'")]
+    MissingAssignments(String),
+
+    #[cfg(feature = "rusqlite")]
+    #[error("Error: SQLite '{0}'")]
+    Sqlite(#[from] rusqlite::Error),
+
+    #[cfg(feature = "sqlx")]
+    #[error("Error: sqlx '{0}'")]
+    Sqlx(String),
 }
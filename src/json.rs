@@ -0,0 +1,370 @@
+//! JSON request bodies, with the `json` feature
+//!
+//! The JSON twin of the HTTP query string, for search requests sent as a
+//! body. `name=contains:jo&age=greater:18&age=lesser:65&order=name:asc&limit=20`
+//! is written as:
+//!
+//! ```json
+//! {
+//!   "filter": { "name": { "contains": ["jo"] }, "age": { "greater": 18, "lesser": 65 } },
+//!   "order": ["name:asc"],
+//!   "limit": 20
+//! }
+//! ```
+//!
+//! - A field maps to `{similarity: values}` with one key per condition,
+//!   ANDed in order, or to plain values, which mean `equals`
+//! - Values are a list or a single string, number, boolean or `null` (the
+//!   `null` sentinel), taken literally without URL decoding
+//! - `"and": [...]`, `"or": [...]` and `"not": {...}` hold groups; a group
+//!   item with several keys is their AND
+//! - `filter` may also be a list of objects, read in turn like repeated keys
+//!   in a query string, and a field may map to a list of `{similarity: values}`
+//!   objects, for anything a single object cannot hold twice
+//! - `fields` is a list of names and `cursor` the token from `to_http()`
+
+use crate::config::ParseConfig;
+use crate::error::{Error, Result};
+use crate::query::{
+    Case, Cursor, Fields, Filter, Order, OrderField, Parameter, Parameters, Query, Similarity,
+};
+use serde_json::{Map, Value};
+
+const FILTER: &str = "filter";
+
+impl Query {
+    // {"filter": {"name": {"contains": ["jo"]}}, "order": ["date_created:desc"], "limit": 20}
+    pub fn from_json(body: String) -> Result<Self> {
+        Self::from_json_with_config(body, &ParseConfig::default())
+    }
+
+    /// Parse a JSON request body, resolving `limit` and `offset` against the
+    /// config's bounds and policy
+    ///
+    /// Gives the same `Query` as the equivalent `from_http_with_config` string.
+    pub fn from_json_with_config(body: String, config: &ParseConfig) -> Result<Self> {
+        let value: Value =
+            serde_json::from_str(&body).map_err(|error| Error::InvalidJson(error.to_string()))?;
+        let Value::Object(object) = value else {
+            return Err(expected("an object", "body"));
+        };
+
+        let mut query = Self::new();
+        query.limit = config.initial_limit();
+
+        for (key, value) in object {
+            match key.as_str() {
+                FILTER => parse_filters(&mut query.parameters, value)?,
+                Parameters::ORDER => query.order = parse_order(value)?,
+                Parameters::FIELDS => query.fields = parse_fields(value)?,
+                Parameters::LIMIT => query.limit = config.parse_limit(&scalar(&key, value)?)?,
+                Parameters::OFFSET => query.offset = config.parse_offset(&scalar(&key, value)?)?,
+                Parameters::CURSOR => {
+                    query.cursor = match value {
+                        Value::Null => None,
+                        Value::String(token) => Some(token.parse::<Cursor>()?),
+                        _ => return Err(expected("a token", &key)),
+                    }
+                }
+                _ => return Err(Error::InvalidJson(format!("unknown key '{key}'"))),
+            }
+        }
+
+        if let Some(cursor) = &query.cursor {
+            cursor.check(&query.order)?;
+        }
+
+        Ok(query)
+    }
+
+    /// The query as a JSON request body that `from_json` reads back
+    pub fn to_json(&self) -> String {
+        let mut body = Map::new();
+
+        if let Some(filter) = filters_json(&self.parameters) {
+            body.insert(FILTER.to_string(), filter);
+        }
+
+        if !self.order.inner().is_empty() {
+            let order = self
+                .order
+                .inner()
+                .iter()
+                .map(|(name, direction)| format!("{name}:{direction}"))
+                .collect::<Vec<String>>();
+            body.insert(Parameters::ORDER.to_string(), order.into());
+        }
+
+        if !self.fields.inner().is_empty() {
+            let fields = self.fields.inner().iter().cloned().collect::<Vec<String>>();
+            body.insert(Parameters::FIELDS.to_string(), fields.into());
+        }
+
+        // A cursor replaces the offset
+        if let Some(cursor) = &self.cursor {
+            body.insert(Parameters::CURSOR.to_string(), cursor.to_string().into());
+        }
+        body.insert(Parameters::LIMIT.to_string(), self.limit.into());
+        if self.cursor.is_none() {
+            body.insert(Parameters::OFFSET.to_string(), self.offset.into());
+        }
+
+        Value::Object(body).to_string()
+    }
+}
+
+fn expected(what: &str, key: &str) -> Error {
+    Error::InvalidJson(format!("expected {what} for '{key}'"))
+}
+
+/// A single value as the text a query string would carry
+fn scalar(key: &str, value: Value) -> Result<String> {
+    match value {
+        Value::String(text) => Ok(text),
+        Value::Number(number) => Ok(number.to_string()),
+        Value::Bool(boolean) => Ok(boolean.to_string()),
+        Value::Null => Ok("null".to_string()),
+        _ => Err(expected("a string, number, boolean or null", key)),
+    }
+}
+
+fn parse_values(key: &str, value: Value) -> Result<Vec<String>> {
+    match value {
+        Value::Array(items) => items.into_iter().map(|item| scalar(key, item)).collect(),
+        value => Ok(vec![scalar(key, value)?]),
+    }
+}
+
+/// `{similarity: values}` or a list of them, rather than plain values
+fn is_conditions(value: &Value) -> bool {
+    match value {
+        Value::Object(_) => true,
+        Value::Array(items) => is_condition_list(items),
+        _ => false,
+    }
+}
+
+fn is_condition_list(items: &[Value]) -> bool {
+    !items.is_empty() && items.iter().all(Value::is_object)
+}
+
+/// The conditions on a field, in order
+fn parse_conditions(key: &str, value: Value) -> Result<Vec<Parameter>> {
+    match value {
+        Value::Object(object) => object
+            .into_iter()
+            .map(|(similarity, values)| parse_condition(key, &similarity, values))
+            .collect(),
+        Value::Array(items) if is_condition_list(&items) => {
+            let mut conditions = Vec::new();
+            for item in items {
+                conditions.extend(parse_conditions(key, item)?);
+            }
+            Ok(conditions)
+        }
+        values => Ok(vec![Parameter::init(
+            Similarity::Equals,
+            parse_values(key, values)?,
+        )]),
+    }
+}
+
+fn parse_condition(key: &str, similarity: &str, values: Value) -> Result<Parameter> {
+    let (parsed, case) = Parameter::parse_similarity(similarity)?;
+    let parameter = Parameter::init(parsed, parse_values(key, values)?).with_case(case);
+    if !parameter.is_valid() {
+        return Err(Error::InvalidParameter(format!("{key}: {similarity}")));
+    }
+    Ok(parameter)
+}
+
+/// Read the top-level filter into conditions keyed by field and groups
+fn parse_filters(parameters: &mut Parameters, value: Value) -> Result<()> {
+    let objects = match value {
+        Value::Object(object) => vec![object],
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| match item {
+                Value::Object(object) => Ok(object),
+                _ => Err(expected("an object", FILTER)),
+            })
+            .collect::<Result<Vec<_>>>()?,
+        _ => return Err(expected("an object or a list of objects", FILTER)),
+    };
+
+    for object in objects {
+        for (key, value) in object {
+            if Filter::GROUPS.contains(&key.as_str()) {
+                parameters.group(parse_group(&key, value)?);
+                continue;
+            }
+            if !is_conditions(&value) {
+                // Plain values join an existing equals condition, as repeated
+                // `name=john&name=doe` keys do
+                for value in parse_values(&key, value)? {
+                    parameters.append_value(key.clone(), value);
+                }
+                continue;
+            }
+            for condition in parse_conditions(&key, value)? {
+                // Only add parameters that have values
+                if !condition.is_empty() {
+                    parameters.append(key.clone(), condition);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_group(key: &str, value: Value) -> Result<Filter> {
+    if key == Filter::NOT {
+        return Ok(Filter::Not(Box::new(parse_filter(key, value)?)));
+    }
+
+    let Value::Array(items) = value else {
+        return Err(expected("a list of filters", key));
+    };
+    let filters = items
+        .into_iter()
+        .map(|item| parse_filter(key, item))
+        .collect::<Result<Vec<Filter>>>()?;
+    if key == Filter::AND {
+        Ok(Filter::And(filters))
+    } else {
+        Ok(Filter::Or(filters))
+    }
+}
+
+/// An item of a group; several keys are combined with AND
+fn parse_filter(group: &str, value: Value) -> Result<Filter> {
+    let Value::Object(object) = value else {
+        return Err(expected("a filter object", group));
+    };
+    if object.is_empty() {
+        return Err(Error::InvalidFilter(format!("{group}: {{}}")));
+    }
+
+    let mut filters = Vec::new();
+    for (key, value) in object {
+        if Filter::GROUPS.contains(&key.as_str()) {
+            filters.push(parse_group(&key, value)?);
+            continue;
+        }
+        let conditions = parse_conditions(&key, value)?;
+        if conditions.is_empty() {
+            return Err(Error::InvalidFilter(format!("{key}: {{}}")));
+        }
+        for condition in conditions {
+            filters.push(Filter::Condition(key.clone(), condition));
+        }
+    }
+
+    if filters.len() == 1 {
+        Ok(filters.remove(0))
+    } else {
+        Ok(Filter::And(filters))
+    }
+}
+
+fn parse_order(value: Value) -> Result<Order> {
+    let Value::Array(items) = value else {
+        return Err(expected("a list of \"field:direction\"", Parameters::ORDER));
+    };
+    let mut order = Order::new();
+    for item in items {
+        let Value::String(text) = item else {
+            return Err(expected("a list of \"field:direction\"", Parameters::ORDER));
+        };
+        let field = text.parse::<OrderField>()?;
+        order
+            .inner_mut()
+            .insert(field.name().clone(), field.sort_direction().clone());
+    }
+    Ok(order)
+}
+
+fn parse_fields(value: Value) -> Result<Fields> {
+    let Value::Array(items) = value else {
+        return Err(expected("a list of names", Parameters::FIELDS));
+    };
+    let mut fields = Fields::new();
+    for item in items {
+        let Value::String(name) = item else {
+            return Err(expected("a list of names", Parameters::FIELDS));
+        };
+        fields.add(name);
+    }
+    Ok(fields)
+}
+
+fn filters_json(parameters: &Parameters) -> Option<Value> {
+    let entries = parameters
+        .inner()
+        .iter()
+        .map(|(key, conditions)| (key.clone(), parameter_json(conditions)))
+        .chain(parameters.groups().iter().map(filter_entry));
+
+    // A key already in the object starts the next one, as a repeated key
+    // would in a query string
+    let mut objects: Vec<Map<String, Value>> = Vec::new();
+    for (key, value) in entries {
+        match objects.last_mut() {
+            Some(object) if !object.contains_key(&key) => {
+                object.insert(key, value);
+            }
+            _ => objects.push(Map::from_iter([(key, value)])),
+        }
+    }
+
+    match objects.len() {
+        0 => None,
+        1 => objects.pop().map(Value::Object),
+        _ => Some(objects.into_iter().map(Value::Object).collect()),
+    }
+}
+
+fn filter_entry(filter: &Filter) -> (String, Value) {
+    match filter {
+        Filter::Condition(key, parameter) => {
+            (key.clone(), parameter_json(std::slice::from_ref(parameter)))
+        }
+        Filter::And(filters) => (Filter::AND.to_string(), filters_list(filters)),
+        Filter::Or(filters) => (Filter::OR.to_string(), filters_list(filters)),
+        Filter::Not(filter) => (Filter::NOT.to_string(), filter_json(filter)),
+    }
+}
+
+fn filter_json(filter: &Filter) -> Value {
+    Value::Object(Map::from_iter([filter_entry(filter)]))
+}
+
+fn filters_list(filters: &[Filter]) -> Value {
+    filters.iter().map(filter_json).collect()
+}
+
+/// `{similarity: values}`, or a list of single conditions when a similarity
+/// repeats
+fn parameter_json(conditions: &[Parameter]) -> Value {
+    let entries = conditions
+        .iter()
+        .map(|condition| {
+            let similarity = match condition.case() {
+                Case::Sensitive => condition.similarity().to_string(),
+                Case::Insensitive => {
+                    format!("{}{}", condition.similarity(), Case::INSENSITIVE_SUFFIX)
+                }
+            };
+            (similarity, Value::from(condition.values().clone()))
+        })
+        .collect::<Vec<(String, Value)>>();
+
+    let object = Map::from_iter(entries.iter().cloned());
+    if object.len() == entries.len() {
+        return Value::Object(object);
+    }
+    entries
+        .into_iter()
+        .map(|entry| Value::Object(Map::from_iter([entry])))
+        .collect()
+}
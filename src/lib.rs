@@ -1,5 +1,19 @@
+#[cfg(any(feature = "http", feature = "json"))]
+mod config;
+#[cfg(feature = "sql")]
+mod date;
 pub mod error;
+#[cfg(feature = "json")]
+mod json;
 mod query;
+mod schema;
+#[cfg(feature = "serde")]
+mod serde_impl;
 #[cfg(feature = "sql")]
 pub mod sql;
+#[cfg(feature = "http")]
+pub mod syntax;
+#[cfg(any(feature = "http", feature = "json"))]
+pub use config::*;
 pub use query::*;
+pub use schema::*;
@@ -1,7 +1,13 @@
+#[cfg(feature = "http")]
+use crate::config::ParseConfig;
 use crate::error::{Error, Result};
+#[cfg(feature = "http")]
+use crate::schema::Schema;
 #[cfg(feature = "sql")]
 use crate::sql;
-use indexmap::IndexMap;
+#[cfg(feature = "http")]
+use crate::syntax::Syntax;
+use indexmap::{IndexMap, IndexSet};
 use std::fmt;
 use std::str::FromStr;
 use url::form_urlencoded;
@@ -11,8 +17,28 @@ use url::form_urlencoded;
 pub struct Query {
     pub parameters: Parameters,
     pub order: Order,
+    /// Fields to return; empty selects every field
+    pub fields: Fields,
     pub limit: usize,
     pub offset: usize,
+    /// Keyset pagination position; when set, `offset` is ignored
+    pub cursor: Option<Cursor>,
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A query with only filters, e.g. for an `UPDATE` or `DELETE`
+impl From<Parameters> for Query {
+    fn from(parameters: Parameters) -> Self {
+        Self {
+            parameters,
+            ..Self::new()
+        }
+    }
 }
 
 impl Query {
@@ -20,8 +46,10 @@ impl Query {
         Self {
             parameters: Parameters::new(),
             order: Order::new(),
+            fields: Fields::new(),
             limit: Parameters::DEFAULT_LIMIT,
             offset: Parameters::DEFAULT_OFFSET,
+            cursor: None,
         }
     }
 
@@ -29,8 +57,10 @@ impl Query {
         Self {
             parameters,
             order,
+            fields: Fields::new(),
             limit,
             offset,
+            cursor: None,
         }
     }
 
@@ -38,6 +68,7 @@ impl Query {
     pub fn to_http(&self) -> String {
         let params_str = format!("{}", self.parameters);
         let order_str = format!("{}", self.order);
+        let fields_str = format!("{}", self.fields);
 
         let mut result = String::new();
 
@@ -51,13 +82,28 @@ impl Query {
             result.push(AMPERSAND);
         }
 
-        let pagination_str = format!(
-            "{}{EQUAL}{}{AMPERSAND}{}{EQUAL}{}",
-            Parameters::LIMIT,
-            self.limit,
-            Parameters::OFFSET,
-            self.offset,
-        );
+        if !fields_str.is_empty() {
+            result.push_str(&format!("{}{EQUAL}{}", Parameters::FIELDS, fields_str));
+            result.push(AMPERSAND);
+        }
+
+        // A cursor replaces the offset
+        let pagination_str = match &self.cursor {
+            Some(cursor) => format!(
+                "{}{EQUAL}{}{AMPERSAND}{}{EQUAL}{}",
+                Parameters::CURSOR,
+                cursor,
+                Parameters::LIMIT,
+                self.limit,
+            ),
+            None => format!(
+                "{}{EQUAL}{}{AMPERSAND}{}{EQUAL}{}",
+                Parameters::LIMIT,
+                self.limit,
+                Parameters::OFFSET,
+                self.offset,
+            ),
+        };
 
         result.push_str(&pagination_str);
         result
@@ -66,7 +112,18 @@ impl Query {
     // name=contains:damian&surname=equals:black,steel,wood&order=date_created:desc&limit=40&offset=0
     #[cfg(feature = "http")]
     pub fn from_http(search: String) -> Result<Self> {
+        Self::from_http_with_config(search, &ParseConfig::default())
+    }
+
+    /// Parse an HTTP query string, resolving `limit` and `offset` against the
+    /// config's bounds and policy
+    ///
+    /// # Examples
+    /// - `Query::from_http_with_config("limit=500".into(), &ParseConfig::new().max_limit(100))` -> limit 100
+    #[cfg(feature = "http")]
+    pub fn from_http_with_config(search: String, config: &ParseConfig) -> Result<Self> {
         let mut query = Self::new();
+        query.limit = config.initial_limit();
         let trimmed_search = search.trim_start_matches(QUESTION).trim();
 
         if trimmed_search.is_empty() {
@@ -101,11 +158,21 @@ impl Query {
                         }
                         // Skip malformed sort fields (like ":desc")
                     }
+                    Parameters::FIELDS => {
+                        query.fields = trimmed_value.parse::<Fields>()?;
+                    }
                     Parameters::LIMIT => {
-                        query.limit = trimmed_value.parse().unwrap_or(Parameters::DEFAULT_LIMIT);
+                        query.limit = config.parse_limit(trimmed_value)?;
                     }
                     Parameters::OFFSET => {
-                        query.offset = trimmed_value.parse().unwrap_or(Parameters::DEFAULT_OFFSET);
+                        query.offset = config.parse_offset(trimmed_value)?;
+                    }
+                    Parameters::CURSOR => {
+                        query.cursor = Some(trimmed_value.parse::<Cursor>()?);
+                    }
+                    Filter::AND | Filter::OR | Filter::NOT => {
+                        let filter = trimmed_kv.parse::<Filter>()?;
+                        query.parameters.group(filter);
                     }
                     _k => {
                         // Check if this is a similarity-based parameter (contains colon)
@@ -113,31 +180,16 @@ impl Query {
                             // Parse as similarity-based parameter
                             let param = trimmed_value.parse::<Parameter>()?;
                             // Only add parameters that have values
-                            if param.values().is_empty() {
+                            if param.is_empty() {
                                 continue;
                             }
-                            // Replace any existing parameter (similarity-based takes precedence)
-                            query.parameters.0.insert(trimmed_key.to_string(), param);
+                            // Repeated keys add further conditions on the same field
+                            query.parameters.append(trimmed_key.to_string(), param);
                         } else {
                             // Handle as normal query parameter (default to equals similarity)
-                            let decoded_value = url_decode(trimmed_value);
-
-                            // Check if parameter already exists and is not similarity-based
-                            if let Some(existing_param) =
-                                query.parameters.0.get_mut(&trimmed_key.to_string())
-                            {
-                                // Only append if the existing parameter is also equals similarity
-                                if *existing_param.similarity() == Similarity::Equals {
-                                    existing_param.1.push(decoded_value);
-                                }
-                                // If existing parameter is similarity-based, ignore this normal parameter
-                            } else {
-                                // Create new parameter with equals similarity
-                                query.parameters.0.insert(
-                                    trimmed_key.to_string(),
-                                    Parameter::init(Similarity::Equals, vec![decoded_value]),
-                                );
-                            }
+                            query
+                                .parameters
+                                .append_value(trimmed_key.to_string(), url_decode(trimmed_value));
                         }
                     }
                 }
@@ -146,336 +198,391 @@ impl Query {
             }
         }
 
+        if let Some(cursor) = &query.cursor {
+            cursor.check(&query.order)?;
+        }
+
         Ok(query)
     }
 
-    #[cfg(feature = "sql")]
-    pub fn to_sql(&self) -> String {
-        let mut sql_parts = Vec::new();
+    /// Parse a query string written in another syntax
+    ///
+    /// # Examples
+    /// - `Query::from_http_with_syntax("filter[name][contains]=jo&page[size]=20".into(), &syntax::JsonApi::new())`
+    #[cfg(feature = "http")]
+    pub fn from_http_with_syntax(search: String, syntax: &dyn Syntax) -> Result<Self> {
+        syntax.parse(&search, &ParseConfig::default())
+    }
 
-        // Build WHERE clause from parameters
-        if let Some(where_clause) = self.where_clause() {
-            sql_parts.push(format!("WHERE {}", where_clause));
-        }
+    /// Write the query string of another syntax
+    #[cfg(feature = "http")]
+    pub fn to_http_with_syntax(&self, syntax: &dyn Syntax) -> String {
+        syntax.render(self)
+    }
 
-        // Build ORDER BY clause from order
-        if let Some(order_clause) = self.order_clause() {
-            sql_parts.push(format!("ORDER BY {}", order_clause));
-        }
+    /// Parse an HTTP query string and reject any parameter, order or fields key
+    /// that the schema does not declare
+    #[cfg(feature = "http")]
+    pub fn from_http_with_schema(search: String, schema: &Schema) -> Result<Self> {
+        let query = Self::from_http(search)?;
+        schema.validate(&query)?;
+        Ok(query)
+    }
 
-        // Add LIMIT and OFFSET
-        sql_parts.push(format!("LIMIT ? OFFSET ?"));
+    #[cfg(feature = "sql")]
+    pub fn renderer(&self) -> sql::Renderer<'_> {
+        sql::Renderer::new(self)
+    }
 
-        sql_parts.join(" ")
+    /// Build a complete `SELECT` statement from `table`
+    #[cfg(feature = "sql")]
+    pub fn select(&self, table: &str) -> sql::Select<'_> {
+        self.renderer().select(table)
     }
 
+    /// Build an `UPDATE` of the rows in `table` matching the filters
     #[cfg(feature = "sql")]
-    pub fn where_clause(&self) -> Option<String> {
-        let mut conditions = Vec::new();
+    pub fn update(&self, table: &str) -> sql::Update<'_> {
+        self.renderer().update(table)
+    }
 
-        for (key, param) in &self.parameters.0 {
-            let similarity = param.similarity();
-            let values = param.values();
-            if values.is_empty() {
-                continue;
-            }
+    /// Build a `DELETE` of the rows in `table` matching the filters
+    #[cfg(feature = "sql")]
+    pub fn delete(&self, table: &str) -> sql::Delete<'_> {
+        self.renderer().delete(table)
+    }
 
-            let condition = match similarity {
-                Similarity::Equals => {
-                    if values.len() == 1 {
-                        if values[0] == sql::NULL {
-                            format!("{} IS ?", key)
-                        } else {
-                            format!("{} = ?", key)
-                        }
-                    } else {
-                        let placeholders = vec!["?"; values.len()].join(", ");
-                        format!("{} IN ({})", key, placeholders)
-                    }
-                }
-                Similarity::Contains => {
-                    if values.len() == 1 {
-                        format!("{} LIKE ?", key)
-                    } else {
-                        let like_conditions: Vec<String> =
-                            values.iter().map(|_| format!("{} LIKE ?", key)).collect();
-                        format!("({})", like_conditions.join(" OR "))
-                    }
-                }
-                Similarity::StartsWith => {
-                    if values.len() == 1 {
-                        format!("{} LIKE ?", key)
-                    } else {
-                        let like_conditions: Vec<String> =
-                            values.iter().map(|_| format!("{} LIKE ?", key)).collect();
-                        format!("({})", like_conditions.join(" OR "))
-                    }
-                }
-                Similarity::EndsWith => {
-                    if values.len() == 1 {
-                        format!("{} LIKE ?", key)
-                    } else {
-                        let like_conditions: Vec<String> =
-                            values.iter().map(|_| format!("{} LIKE ?", key)).collect();
-                        format!("({})", like_conditions.join(" OR "))
-                    }
-                }
-                Similarity::Between => {
-                    if values.len() >= 2 {
-                        // Group values into pairs, ignoring any odd value
-                        let pairs: Vec<&[String]> = values.chunks(2).collect();
-                        let between_conditions: Vec<String> = pairs
-                            .iter()
-                            .map(|pair| {
-                                if pair.len() == 2 {
-                                    format!("{} BETWEEN ? AND ?", key)
-                                } else {
-                                    String::new() // Skip incomplete pairs
-                                }
-                            })
-                            .filter(|condition| !condition.is_empty())
-                            .collect();
-
-                        if between_conditions.is_empty() {
-                            continue; // Skip if no valid pairs
-                        } else if between_conditions.len() == 1 {
-                            between_conditions[0].clone()
-                        } else {
-                            format!("({})", between_conditions.join(" OR "))
-                        }
-                    } else {
-                        continue; // Skip invalid between conditions
-                    }
-                }
-                Similarity::Lesser => {
-                    if values.len() == 1 {
-                        format!("{} < ?", key)
-                    } else {
-                        let conditions: Vec<String> =
-                            values.iter().map(|_| format!("{} < ?", key)).collect();
-                        format!("({})", conditions.join(" OR "))
-                    }
-                }
-                Similarity::LesserOrEqual => {
-                    if values.len() == 1 {
-                        format!("{} <= ?", key)
-                    } else {
-                        let conditions: Vec<String> =
-                            values.iter().map(|_| format!("{} <= ?", key)).collect();
-                        format!("({})", conditions.join(" OR "))
-                    }
-                }
-                Similarity::Greater => {
-                    if values.len() == 1 {
-                        format!("{} > ?", key)
-                    } else {
-                        let conditions: Vec<String> =
-                            values.iter().map(|_| format!("{} > ?", key)).collect();
-                        format!("({})", conditions.join(" OR "))
-                    }
-                }
-                Similarity::GreaterOrEqual => {
-                    if values.len() == 1 {
-                        format!("{} >= ?", key)
-                    } else {
-                        let conditions: Vec<String> =
-                            values.iter().map(|_| format!("{} >= ?", key)).collect();
-                        format!("({})", conditions.join(" OR "))
-                    }
-                }
-            };
+    /// Whether an item passes the filters, as the WHERE clause would decide
+    #[cfg(feature = "sql")]
+    pub fn matches<T: sql::Filterable>(&self, item: &T) -> Result<bool> {
+        self.renderer().matches(item)
+    }
 
-            conditions.push(condition);
-        }
+    /// Filter, sort and paginate items in memory, returning the rows the
+    /// SELECT would
+    ///
+    /// # Examples
+    /// - `query.apply(cached_users.iter().cloned())?`
+    #[cfg(feature = "sql")]
+    pub fn apply<T, I>(&self, items: I) -> Result<Vec<T>>
+    where
+        T: sql::Filterable,
+        I: IntoIterator<Item = T>,
+    {
+        self.renderer().apply(items)
+    }
 
-        if conditions.is_empty() {
-            None
-        } else {
-            Some(conditions.join(" AND "))
-        }
+    /// Run `SELECT ... FROM <table>` and map every row
+    ///
+    /// # Examples
+    /// - `query.fetch(&connection, "users", |row| row.get::<_, String>("name"))`
+    #[cfg(feature = "rusqlite")]
+    pub fn fetch<T, F>(
+        &self,
+        connection: &rusqlite::Connection,
+        table: &str,
+        map: F,
+    ) -> Result<Vec<T>>
+    where
+        F: FnMut(&rusqlite::Row<'_>) -> rusqlite::Result<T>,
+    {
+        self.select(table).fetch(connection, map)
     }
 
-    #[cfg(feature = "sql")]
-    pub fn order_clause(&self) -> Option<String> {
-        let mut order_parts = Vec::new();
+    /// Run `SELECT ... FROM <table>` with its count, returning the rows, the
+    /// total and the query for the next page
+    #[cfg(feature = "rusqlite")]
+    pub fn fetch_page<T, F>(
+        &self,
+        connection: &rusqlite::Connection,
+        table: &str,
+        map: F,
+    ) -> Result<sql::Page<T>>
+    where
+        F: FnMut(&rusqlite::Row<'_>) -> rusqlite::Result<T>,
+    {
+        self.select(table).fetch_page(connection, map)
+    }
 
-        for (name, direction) in &self.order.0 {
-            if !name.is_empty() {
-                let direction = match direction {
-                    SortDirection::Ascending => "ASC",
-                    SortDirection::Descending => "DESC",
+    /// Render without a schema, paging by OFFSET when the cursor does not
+    /// match the order (the parsers reject such a cursor, but the fields are
+    /// public). `renderer()` returns the mismatch as an error instead.
+    #[cfg(feature = "sql")]
+    fn render<T>(&self, render: impl Fn(&sql::Renderer<'_>) -> Result<T>) -> T {
+        let rendered = match &self.cursor {
+            Some(cursor) if cursor.check(&self.order).is_err() => {
+                let query = Query {
+                    cursor: None,
+                    ..self.clone()
                 };
-                order_parts.push(format!("{} {}", name, direction));
+                render(&query.renderer())
             }
-        }
+            _ => render(&self.renderer()),
+        };
+        // Without a schema or a mismatched cursor nothing is rejected
+        rendered.expect("rendering without a schema does not fail")
+    }
 
-        if order_parts.is_empty() {
-            None
-        } else {
-            Some(order_parts.join(", "))
-        }
+    #[cfg(feature = "sql")]
+    pub fn to_sql(&self) -> String {
+        self.render(|renderer| renderer.to_sql())
     }
 
     #[cfg(feature = "sql")]
-    pub fn to_values(&self) -> Vec<sql::Value> {
-        let mut sql_values = self.parameter_values();
-        sql_values.extend(self.pagination_values());
-        sql_values
+    pub fn where_clause(&self) -> Option<String> {
+        self.render(|renderer| renderer.where_clause())
     }
 
     #[cfg(feature = "sql")]
-    /// Get SQL values for parameters only (without limit and offset)
-    pub fn parameter_values(&self) -> Vec<sql::Value> {
-        let mut sql_values = Vec::new();
-
-        for (_k, param) in self.parameters.inner() {
-            let param_similarity = param.similarity();
-            let param_values = param.values();
-            for cur_val in param_values {
-                // Skip empty values
-                if cur_val.trim().is_empty() {
-                    continue;
-                }
+    pub fn order_clause(&self) -> Option<String> {
+        self.render(|renderer| renderer.order_clause())
+    }
 
-                if cur_val == sql::NULL {
-                    sql_values.push(sql::Value::Null);
-                    continue;
-                }
+    /// `SELECT COUNT(*) FROM <table> WHERE ...` for the total next to a page
+    #[cfg(feature = "sql")]
+    pub fn to_count_sql(&self, table: &str) -> String {
+        self.render(|renderer| renderer.to_count_sql(table))
+    }
 
-                let sql_value = match *param_similarity {
-                    Similarity::Contains => sql::Value::Text(format!("%{}%", cur_val)),
-                    Similarity::StartsWith => sql::Value::Text(format!("{}%", cur_val)),
-                    Similarity::EndsWith => sql::Value::Text(format!("%{}", cur_val)),
-                    _ => {
-                        // Try to parse as integer first, then float, then text
-                        if let Ok(i) = cur_val.parse::<i64>() {
-                            sql::Value::Integer(i)
-                        } else if let Ok(f) = cur_val.parse::<f64>() {
-                            sql::Value::Real(f)
-                        } else {
-                            sql::Value::Text(cur_val.clone())
-                        }
-                    }
-                };
+    /// Values for `to_count_sql()`, without the cursor, limit or offset
+    #[cfg(feature = "sql")]
+    pub fn count_values(&self) -> Vec<sql::Value> {
+        self.render(|renderer| renderer.count_values())
+    }
 
-                sql_values.push(sql_value);
-            }
-        }
+    #[cfg(feature = "sql")]
+    pub fn to_values(&self) -> Vec<sql::Value> {
+        self.render(|renderer| renderer.to_values())
+    }
 
-        sql_values
+    #[cfg(feature = "sql")]
+    /// Get SQL values for parameters only (without limit and offset)
+    pub fn parameter_values(&self) -> Vec<sql::Value> {
+        self.render(|renderer| renderer.parameter_values())
     }
 
     #[cfg(feature = "sql")]
-    /// Get SQL values for pagination (limit and offset only)
+    /// Get SQL values for pagination (limit and offset, or only limit with a cursor)
     pub fn pagination_values(&self) -> Vec<sql::Value> {
-        vec![
-            sql::Value::Integer(self.limit as i64),
-            sql::Value::Integer(self.offset as i64),
-        ]
+        self.render(|renderer| Ok(renderer.pagination_values()))
     }
 
     #[cfg(feature = "sql")]
     /// Get the total number of SQL parameter values (parameters + pagination)
     /// This counts only non-empty values, matching the behavior of to_values()
     pub fn total_parameters(&self) -> usize {
-        let parameter_count: usize = self
-            .parameters
-            .inner()
-            .values()
-            .map(|param| {
-                param
-                    .values()
-                    .iter()
-                    .filter(|v| !v.trim().is_empty())
-                    .count()
-            })
-            .sum();
-
-        parameter_count + 2 // +2 for limit and offset
+        self.to_values().len()
     }
 }
 
+/// Conditions keyed by field, combined with AND, plus any AND/OR/NOT groups
+/// that are ANDed onto them
 #[derive(Clone, Debug, PartialEq)]
-pub struct Parameters(IndexMap<String, Parameter>);
+pub struct Parameters(IndexMap<String, Vec<Parameter>>, Vec<Filter>);
 
 impl Parameters {
     pub const ORDER: &str = "order";
     pub const LIMIT: &str = "limit";
     pub const OFFSET: &str = "offset";
+    pub const CURSOR: &str = "cursor";
+    pub const FIELDS: &str = "fields";
 
-    pub const EXCLUDE: [&str; 3] = [Parameters::ORDER, Parameters::LIMIT, Parameters::OFFSET];
+    pub const EXCLUDE: [&str; 5] = [
+        Parameters::ORDER,
+        Parameters::FIELDS,
+        Parameters::LIMIT,
+        Parameters::OFFSET,
+        Parameters::CURSOR,
+    ];
 
     pub const DEFAULT_LIMIT: usize = 50;
     pub const DEFAULT_OFFSET: usize = 0;
 
     pub fn new() -> Self {
-        Self(IndexMap::new())
+        Self(IndexMap::new(), Vec::new())
     }
 
-    pub fn inner(&self) -> &IndexMap<String, Parameter> {
+    pub fn inner(&self) -> &IndexMap<String, Vec<Parameter>> {
         &self.0
     }
 
-    pub fn inner_mut(&mut self) -> &mut IndexMap<String, Parameter> {
+    pub fn inner_mut(&mut self) -> &mut IndexMap<String, Vec<Parameter>> {
         &mut self.0
     }
 
+    pub fn groups(&self) -> &Vec<Filter> {
+        &self.1
+    }
+
+    pub fn groups_mut(&mut self) -> &mut Vec<Filter> {
+        &mut self.1
+    }
+
+    /// Add an AND/OR/NOT group, combined with AND with everything else
+    pub fn group(&mut self, filter: Filter) -> &mut Self {
+        self.1.push(filter);
+        self
+    }
+
+    /// Every field referenced by the parameters, including inside groups
+    pub fn fields(&self) -> Vec<&String> {
+        let mut fields: Vec<&String> = self.0.keys().collect();
+        for filter in &self.1 {
+            for (key, _) in filter.conditions() {
+                if !fields.contains(&key) {
+                    fields.push(key);
+                }
+            }
+        }
+        fields
+    }
+
     pub fn equals(&mut self, key: String, values: Vec<String>) -> &mut Self {
         self.0
-            .insert(key, Parameter::init(Similarity::Equals, values));
+            .insert(key, vec![Parameter::init(Similarity::Equals, values)]);
         self
     }
 
     pub fn contains(&mut self, key: String, values: Vec<String>) -> &mut Self {
         self.0
-            .insert(key, Parameter::init(Similarity::Contains, values));
+            .insert(key, vec![Parameter::init(Similarity::Contains, values)]);
         self
     }
 
     pub fn starts_with(&mut self, key: String, values: Vec<String>) -> &mut Self {
         self.0
-            .insert(key, Parameter::init(Similarity::StartsWith, values));
+            .insert(key, vec![Parameter::init(Similarity::StartsWith, values)]);
         self
     }
 
     pub fn ends_with(&mut self, key: String, values: Vec<String>) -> &mut Self {
         self.0
-            .insert(key, Parameter::init(Similarity::EndsWith, values));
+            .insert(key, vec![Parameter::init(Similarity::EndsWith, values)]);
+        self
+    }
+
+    /// Match a raw LIKE pattern; `%` and `_` in the values act as wildcards
+    pub fn like(&mut self, key: String, values: Vec<String>) -> &mut Self {
+        self.0
+            .insert(key, vec![Parameter::init(Similarity::Like, values)]);
+        self
+    }
+
+    pub fn not_equals(&mut self, key: String, values: Vec<String>) -> &mut Self {
+        self.0
+            .insert(key, vec![Parameter::init(Similarity::NotEquals, values)]);
+        self
+    }
+
+    pub fn not_contains(&mut self, key: String, values: Vec<String>) -> &mut Self {
+        self.0
+            .insert(key, vec![Parameter::init(Similarity::NotContains, values)]);
+        self
+    }
+
+    pub fn not_starts_with(&mut self, key: String, values: Vec<String>) -> &mut Self {
+        self.0.insert(
+            key,
+            vec![Parameter::init(Similarity::NotStartsWith, values)],
+        );
+        self
+    }
+
+    pub fn not_ends_with(&mut self, key: String, values: Vec<String>) -> &mut Self {
+        self.0
+            .insert(key, vec![Parameter::init(Similarity::NotEndsWith, values)]);
         self
     }
 
     pub fn between(&mut self, key: String, values: Vec<String>) -> &mut Self {
         self.0
-            .insert(key, Parameter::init(Similarity::Between, values));
+            .insert(key, vec![Parameter::init(Similarity::Between, values)]);
         self
     }
 
     pub fn lesser(&mut self, key: String, values: Vec<String>) -> &mut Self {
         self.0
-            .insert(key, Parameter::init(Similarity::Lesser, values));
+            .insert(key, vec![Parameter::init(Similarity::Lesser, values)]);
         self
     }
 
     pub fn lesser_or_equal(&mut self, key: String, values: Vec<String>) -> &mut Self {
-        self.0
-            .insert(key, Parameter::init(Similarity::LesserOrEqual, values));
+        self.0.insert(
+            key,
+            vec![Parameter::init(Similarity::LesserOrEqual, values)],
+        );
         self
     }
 
     pub fn greater(&mut self, key: String, values: Vec<String>) -> &mut Self {
         self.0
-            .insert(key, Parameter::init(Similarity::Greater, values));
+            .insert(key, vec![Parameter::init(Similarity::Greater, values)]);
         self
     }
 
     pub fn greater_or_equal(&mut self, key: String, values: Vec<String>) -> &mut Self {
+        self.0.insert(
+            key,
+            vec![Parameter::init(Similarity::GreaterOrEqual, values)],
+        );
+        self
+    }
+
+    pub fn not_between(&mut self, key: String, values: Vec<String>) -> &mut Self {
+        self.0
+            .insert(key, vec![Parameter::init(Similarity::NotBetween, values)]);
+        self
+    }
+
+    pub fn is_null(&mut self, key: String) -> &mut Self {
         self.0
-            .insert(key, Parameter::init(Similarity::GreaterOrEqual, values));
+            .insert(key, vec![Parameter::init(Similarity::IsNull, vec![])]);
+        self
+    }
+
+    pub fn is_not_null(&mut self, key: String) -> &mut Self {
+        self.0
+            .insert(key, vec![Parameter::init(Similarity::IsNotNull, vec![])]);
+        self
+    }
+
+    /// Add a condition to a key, combined with AND with any conditions the key
+    /// already has. Unlike the similarity builders this never replaces.
+    pub fn append(&mut self, key: String, parameter: Parameter) -> &mut Self {
+        self.0.entry(key).or_default().push(parameter);
+        self
+    }
+
+    /// Add a plain `key=value`, merged into an existing case-sensitive equals
+    /// condition so that repeated keys mean `IN`
+    #[cfg(any(feature = "http", feature = "json"))]
+    pub(crate) fn append_value(&mut self, key: String, value: String) -> &mut Self {
+        let existing_values = self
+            .0
+            .get_mut(&key)
+            .and_then(|conditions| {
+                conditions.iter_mut().find(|condition| {
+                    condition.0 == Similarity::Equals && condition.2 == Case::Sensitive
+                })
+            })
+            .map(|condition| &mut condition.1);
+
+        match existing_values {
+            Some(existing_values) => existing_values.push(value),
+            None => {
+                self.append(key, Parameter::init(Similarity::Equals, vec![value]));
+            }
+        }
         self
     }
 
     pub fn keep(&self, keys: Vec<String>) -> Self {
         let mut result = Self::new();
+        result.1 = self
+            .1
+            .iter()
+            .filter_map(|filter| filter.retain(&|key| keys.contains(key)))
+            .collect();
         for key in keys {
             if let Some(value) = self.0.get(&key) {
                 result.0.insert(key, value.clone());
@@ -486,6 +593,11 @@ impl Parameters {
 
     pub fn remove(&self, keys: Vec<String>) -> Self {
         let mut result = self.clone();
+        result.1 = self
+            .1
+            .iter()
+            .filter_map(|filter| filter.retain(&|key| !keys.contains(key)))
+            .collect();
         for key in keys {
             result.0.shift_remove(&key);
         }
@@ -530,13 +642,18 @@ impl FromStr for Parameters {
                 continue;
             }
 
+            if Filter::GROUPS.contains(&trimmed_key) {
+                parameters.group(trimmed_param.parse::<Filter>()?);
+                continue;
+            }
+
             let param = value.parse::<Parameter>()?;
             // Only add parameters that have values
-            if param.values().is_empty() {
+            if param.is_empty() {
                 continue;
             }
 
-            parameters.0.insert(trimmed_key.to_string(), param);
+            parameters.append(trimmed_key.to_string(), param);
         }
 
         Ok(parameters)
@@ -548,26 +665,42 @@ impl fmt::Display for Parameters {
         let params_str = self
             .inner()
             .iter()
-            .filter(|(_, param)| param.values().len() > 0)
-            .map(|(key, param)| format!("{key}{EQUAL}{param}"))
+            .flat_map(|(key, conditions)| conditions.iter().map(move |condition| (key, condition)))
+            .filter(|(_, condition)| !condition.is_empty())
+            .map(|(key, condition)| format!("{key}{EQUAL}{condition}"))
+            .chain(self.groups().iter().map(|filter| format!("{filter}")))
             .collect::<Vec<String>>()
             .join(&format!("{AMPERSAND}"));
         write!(f, "{}", params_str)
     }
 }
 
+/// A similarity and its values, compared with the given case
 #[derive(Clone, Debug, PartialEq)]
-pub struct Parameter(Similarity, Vec<String>);
+pub struct Parameter(Similarity, Vec<String>, Case);
 
 impl Parameter {
     pub fn init(similarity: Similarity, values: Vec<String>) -> Self {
-        Self(similarity, values)
+        Self(similarity, values, Case::Sensitive)
+    }
+
+    /// Set how this condition compares text
+    ///
+    /// # Examples
+    /// - `Parameter::init(Similarity::Equals, vec!["John".into()]).with_case(Case::Insensitive)`
+    pub fn with_case(mut self, case: Case) -> Self {
+        self.2 = case;
+        self
     }
 
     pub fn similarity(&self) -> &Similarity {
         &self.0
     }
 
+    pub fn case(&self) -> &Case {
+        &self.2
+    }
+
     pub fn values(&self) -> &Vec<String> {
         &self.1
     }
@@ -575,6 +708,27 @@ impl Parameter {
     pub fn values_mut(&mut self) -> &mut Vec<String> {
         &mut self.1
     }
+
+    /// Whether the condition has nothing to compare against, i.e. it needs
+    /// values but has none
+    pub fn is_empty(&self) -> bool {
+        self.0.takes_values() && self.1.is_empty()
+    }
+
+    /// Split a similarity from its `-ci` suffix, e.g. `equals-ci`
+    pub(crate) fn parse_similarity(s: &str) -> Result<(Similarity, Case)> {
+        let (similarity_str, case) = match s.strip_suffix(Case::INSENSITIVE_SUFFIX) {
+            Some(similarity_str) => (similarity_str, Case::Insensitive),
+            None => (s, Case::Sensitive),
+        };
+        Ok((similarity_str.parse::<Similarity>()?, case))
+    }
+
+    /// Whether the values and case suit the similarity; `is-null` and
+    /// `is-not-null` take neither
+    pub(crate) fn is_valid(&self) -> bool {
+        self.0.takes_values() || (self.1.is_empty() && self.2 == Case::Sensitive)
+    }
 }
 
 impl FromStr for Parameter {
@@ -586,13 +740,17 @@ impl FromStr for Parameter {
     /// - "contains:damian" -> Parameter(Similarity::Contains, vec!["damian"])
     /// - "equals:black,steel,wood" -> Parameter(Similarity::Equals, vec!["black", "steel", "wood"])
     /// - "between:20,30" -> Parameter(Similarity::Between, vec!["20", "30"])
+    /// - "is-null:" -> Parameter(Similarity::IsNull, vec![])
+    /// - "equals-ci:john" -> Parameter(Similarity::Equals, vec!["john"]) compared case-insensitively
     fn from_str(s: &str) -> Result<Self> {
         let trimmed = s.trim();
         if trimmed.is_empty() {
             return Err(Error::InvalidParameter(s.into()));
         }
 
-        let parts: Vec<&str> = trimmed.split(COLON).collect();
+        // Only the first colon separates the similarity, so values such as
+        // times may contain more
+        let parts: Vec<&str> = trimmed.splitn(2, COLON).collect();
         if parts.len() != 2 {
             return Err(Error::InvalidParameter(s.into()));
         }
@@ -614,14 +772,21 @@ impl FromStr for Parameter {
                 .collect()
         };
 
-        let similarity = similarity_str.parse::<Similarity>()?;
-        Ok(Parameter(similarity, values))
+        let (similarity, case) = Parameter::parse_similarity(similarity_str)?;
+        let parameter = Parameter::init(similarity, values).with_case(case);
+        if !parameter.is_valid() {
+            return Err(Error::InvalidParameter(s.into()));
+        }
+        Ok(parameter)
     }
 }
 
 impl fmt::Display for Parameter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let similarity_str = self.similarity().to_string();
+        let similarity_str = match self.case() {
+            Case::Sensitive => self.similarity().to_string(),
+            Case::Insensitive => format!("{}{}", self.similarity(), Case::INSENSITIVE_SUFFIX),
+        };
         let values_str = self
             .values()
             .iter()
@@ -632,6 +797,166 @@ impl fmt::Display for Parameter {
     }
 }
 
+/// A boolean expression over conditions, used for AND/OR/NOT groups
+///
+/// In HTTP form a group is written as `or=(status=equals:draft,owner=equals:me)`
+/// and groups can be nested, e.g. `not=(or=(a=equals:1,b=equals:2))`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Filter {
+    Condition(String, Parameter),
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+    Not(Box<Filter>),
+}
+
+impl Filter {
+    pub const AND: &str = "and";
+    pub const OR: &str = "or";
+    pub const NOT: &str = "not";
+
+    pub const GROUPS: [&str; 3] = [Filter::AND, Filter::OR, Filter::NOT];
+
+    /// Every condition in the expression, depth first
+    pub fn conditions(&self) -> Vec<(&String, &Parameter)> {
+        match self {
+            Self::Condition(key, param) => vec![(key, param)],
+            Self::And(filters) | Self::Or(filters) => filters
+                .iter()
+                .flat_map(|filter| filter.conditions())
+                .collect(),
+            Self::Not(filter) => filter.conditions(),
+        }
+    }
+
+    /// Copy of the expression with only the conditions whose key passes the
+    /// predicate, or `None` if nothing is left
+    pub(crate) fn retain(&self, predicate: &dyn Fn(&String) -> bool) -> Option<Self> {
+        match self {
+            Self::Condition(key, _) => predicate(key).then(|| self.clone()),
+            Self::And(filters) => {
+                let filters: Vec<Filter> =
+                    filters.iter().filter_map(|f| f.retain(predicate)).collect();
+                (!filters.is_empty()).then_some(Self::And(filters))
+            }
+            Self::Or(filters) => {
+                let filters: Vec<Filter> =
+                    filters.iter().filter_map(|f| f.retain(predicate)).collect();
+                (!filters.is_empty()).then_some(Self::Or(filters))
+            }
+            Self::Not(filter) => filter.retain(predicate).map(|f| Self::Not(Box::new(f))),
+        }
+    }
+}
+
+impl FromStr for Filter {
+    type Err = Error;
+
+    // EXAMPLE INPUT
+    // or=(status=equals:draft,and=(owner=equals:me,age=greater:18))
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        let mut parts = trimmed.splitn(2, EQUAL);
+        let (key, value) = match (parts.next(), parts.next()) {
+            (Some(k), Some(v)) if !k.trim().is_empty() && !v.trim().is_empty() => {
+                (k.trim(), v.trim())
+            }
+            _ => return Err(Error::InvalidFilter(s.into())),
+        };
+
+        if !Filter::GROUPS.contains(&key) {
+            let param = if value.contains(COLON) {
+                value.parse::<Parameter>()?
+            } else {
+                Parameter::init(Similarity::Equals, vec![url_decode(value)])
+            };
+            return Ok(Filter::Condition(key.to_string(), param));
+        }
+
+        let inner = value
+            .strip_prefix(OPEN_PAREN)
+            .and_then(|v| v.strip_suffix(CLOSE_PAREN))
+            .ok_or_else(|| Error::InvalidFilter(s.into()))?;
+
+        let mut filters = Vec::new();
+        for item in split_group(inner).ok_or_else(|| Error::InvalidFilter(s.into()))? {
+            filters.push(item.parse::<Filter>()?);
+        }
+
+        match key {
+            Filter::AND => Ok(Filter::And(filters)),
+            Filter::OR => Ok(Filter::Or(filters)),
+            _ => {
+                if filters.len() == 1 {
+                    Ok(Filter::Not(Box::new(filters.remove(0))))
+                } else {
+                    Ok(Filter::Not(Box::new(Filter::And(filters))))
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let group = |name: &str, filters: &[Filter]| {
+            let items = filters
+                .iter()
+                .map(|filter| format!("{filter}"))
+                .collect::<Vec<String>>()
+                .join(&format!("{COMMA}"));
+            format!("{name}{EQUAL}{OPEN_PAREN}{items}{CLOSE_PAREN}")
+        };
+
+        let s = match self {
+            Self::Condition(key, param) => format!("{key}{EQUAL}{param}"),
+            Self::And(filters) => group(Filter::AND, filters),
+            Self::Or(filters) => group(Filter::OR, filters),
+            Self::Not(filter) => group(Filter::NOT, std::slice::from_ref(filter.as_ref())),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Split the inside of a group into its items. Commas nested in parentheses
+/// belong to inner groups, and a segment without `=` continues the values of
+/// the previous item (e.g. `status=equals:draft,published`).
+fn split_group(inner: &str) -> Option<Vec<String>> {
+    let mut segments = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            OPEN_PAREN => depth += 1,
+            CLOSE_PAREN => depth = depth.checked_sub(1)?,
+            COMMA if depth == 0 => {
+                segments.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return None;
+    }
+    segments.push(&inner[start..]);
+
+    let mut items: Vec<String> = Vec::new();
+    for segment in segments
+        .into_iter()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    {
+        if segment.contains(EQUAL) {
+            items.push(segment.to_string());
+        } else {
+            let previous = items.last_mut()?;
+            previous.push(COMMA);
+            previous.push_str(segment);
+        }
+    }
+    Some(items)
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Order(IndexMap<String, SortDirection>);
 
@@ -716,7 +1041,7 @@ impl fmt::Display for Order {
         let order_str = self
             .inner()
             .iter()
-            .filter(|(name, _)| name.len() > 0)
+            .filter(|(name, _)| !name.is_empty())
             .map(|(name, direction)| format!("{}", OrderField(name.clone(), direction.clone())))
             .collect::<Vec<String>>()
             .join(&format!("{COMMA}"));
@@ -724,6 +1049,89 @@ impl fmt::Display for Order {
     }
 }
 
+/// Projection: the fields a query returns, in order. Empty means every field.
+///
+/// Over HTTP it travels as `fields=id,name,email`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fields(IndexSet<String>);
+
+impl Fields {
+    pub fn new() -> Self {
+        Self(IndexSet::new())
+    }
+
+    pub fn inner(&self) -> &IndexSet<String> {
+        &self.0
+    }
+
+    pub fn inner_mut(&mut self) -> &mut IndexSet<String> {
+        &mut self.0
+    }
+
+    pub fn add(&mut self, name: String) -> &mut Self {
+        self.0.insert(name);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn keep(&self, keys: Vec<String>) -> Self {
+        Self(
+            self.0
+                .iter()
+                .filter(|name| keys.contains(name))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    pub fn remove(&self, keys: Vec<String>) -> Self {
+        let mut result = self.clone();
+        for key in keys {
+            result.0.shift_remove(&key);
+        }
+        result
+    }
+}
+
+impl Default for Fields {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromStr for Fields {
+    type Err = Error;
+
+    // EXAMPLE INPUT
+    // id,name,email
+    fn from_str(s: &str) -> Result<Self> {
+        let mut fields = Fields::new();
+        for name in s.split(COMMA) {
+            let name = url_decode(name.trim());
+            if !name.is_empty() {
+                fields.0.insert(name);
+            }
+        }
+        Ok(fields)
+    }
+}
+
+impl fmt::Display for Fields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields_str = self
+            .0
+            .iter()
+            .filter(|name| !name.is_empty())
+            .map(|name| url_encode(name))
+            .collect::<Vec<String>>()
+            .join(&format!("{COMMA}"));
+        write!(f, "{}", fields_str)
+    }
+}
+
 pub struct OrderField(String, SortDirection);
 
 impl OrderField {
@@ -774,18 +1182,84 @@ impl fmt::Display for OrderField {
 }
 
 // Utility enums (needed by main types)
+/// Position after the last row of a page, for keyset pagination
+///
+/// Holds that row's values of the `Order` fields, in the same order. A query
+/// with a cursor seeks past it instead of skipping `offset` rows. Over HTTP it
+/// travels as an opaque token, `cursor=<token>`.
 #[derive(Clone, Debug, PartialEq)]
+pub struct Cursor(Vec<String>);
+
+impl Cursor {
+    pub fn new(values: Vec<String>) -> Self {
+        Self(values)
+    }
+
+    pub fn values(&self) -> &Vec<String> {
+        &self.0
+    }
+
+    /// A cursor holds one value per `Order` field, so it needs an order of the
+    /// same length to seek along
+    #[cfg(any(feature = "sql", feature = "http", feature = "serde", feature = "json"))]
+    pub(crate) fn check(&self, order: &Order) -> Result<()> {
+        let fields = order.inner().keys().filter(|name| !name.is_empty()).count();
+        if fields == 0 || fields != self.0.len() {
+            return Err(Error::InvalidCursor(self.to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = Error;
+
+    /// Decode a token produced by `Display`
+    fn from_str(s: &str) -> Result<Self> {
+        let bytes = base64_url_decode(s.trim()).ok_or_else(|| Error::InvalidCursor(s.into()))?;
+        let values: Vec<String> = form_urlencoded::parse(&bytes)
+            .map(|(_, value)| value.into_owned())
+            .collect();
+        if values.is_empty() {
+            return Err(Error::InvalidCursor(s.into()));
+        }
+        Ok(Self(values))
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.0.iter().map(|value| (CURSOR_VALUE, value)))
+            .finish();
+        write!(f, "{}", base64_url_encode(encoded.as_bytes()))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum Similarity {
+    #[default]
     Equals,
     Contains,
     StartsWith,
     EndsWith,
+    Like,
+
+    NotEquals,
+    NotContains,
+    NotStartsWith,
+    NotEndsWith,
 
     Between,
     Lesser,
     LesserOrEqual,
     Greater,
     GreaterOrEqual,
+
+    NotBetween,
+
+    IsNull,
+    IsNotNull,
 }
 
 impl Similarity {
@@ -793,17 +1267,28 @@ impl Similarity {
     pub const CONTAINS: &str = "contains";
     pub const STARTS_WITH: &str = "starts-with";
     pub const ENDS_WITH: &str = "ends-with";
+    pub const LIKE: &str = "like";
+
+    pub const NOT_EQUALS: &str = "not-equals";
+    pub const NOT_CONTAINS: &str = "not-contains";
+    pub const NOT_STARTS_WITH: &str = "not-starts-with";
+    pub const NOT_ENDS_WITH: &str = "not-ends-with";
 
     pub const BETWEEN: &str = "between";
     pub const LESSER: &str = "lesser";
     pub const LESSER_OR_EQUAL: &str = "lesser-or-equal";
     pub const GREATER: &str = "greater";
     pub const GREATER_OR_EQUAL: &str = "greater-or-equal";
-}
 
-impl Default for Similarity {
-    fn default() -> Self {
-        Self::Equals
+    pub const NOT_BETWEEN: &str = "not-between";
+
+    pub const IS_NULL: &str = "is-null";
+    pub const IS_NOT_NULL: &str = "is-not-null";
+
+    /// Whether the similarity compares against values; `is-null` and
+    /// `is-not-null` take none and are written as `deleted_at=is-null:`
+    pub fn takes_values(&self) -> bool {
+        !matches!(self, Similarity::IsNull | Similarity::IsNotNull)
     }
 }
 
@@ -815,6 +1300,12 @@ impl FromStr for Similarity {
             Similarity::CONTAINS => Ok(Similarity::Contains),
             Similarity::STARTS_WITH => Ok(Similarity::StartsWith),
             Similarity::ENDS_WITH => Ok(Similarity::EndsWith),
+            Similarity::LIKE => Ok(Similarity::Like),
+
+            Similarity::NOT_EQUALS => Ok(Similarity::NotEquals),
+            Similarity::NOT_CONTAINS => Ok(Similarity::NotContains),
+            Similarity::NOT_STARTS_WITH => Ok(Similarity::NotStartsWith),
+            Similarity::NOT_ENDS_WITH => Ok(Similarity::NotEndsWith),
 
             Similarity::BETWEEN => Ok(Similarity::Between),
             Similarity::LESSER => Ok(Similarity::Lesser),
@@ -822,6 +1313,11 @@ impl FromStr for Similarity {
             Similarity::GREATER => Ok(Similarity::Greater),
             Similarity::GREATER_OR_EQUAL => Ok(Similarity::GreaterOrEqual),
 
+            Similarity::NOT_BETWEEN => Ok(Similarity::NotBetween),
+
+            Similarity::IS_NULL => Ok(Similarity::IsNull),
+            Similarity::IS_NOT_NULL => Ok(Similarity::IsNotNull),
+
             val => Err(Error::InvalidSimilarity(val.into())),
         }
     }
@@ -834,19 +1330,46 @@ impl fmt::Display for Similarity {
             Self::Contains => Self::CONTAINS,
             Self::StartsWith => Self::STARTS_WITH,
             Self::EndsWith => Self::ENDS_WITH,
+            Self::Like => Self::LIKE,
+
+            Self::NotEquals => Self::NOT_EQUALS,
+            Self::NotContains => Self::NOT_CONTAINS,
+            Self::NotStartsWith => Self::NOT_STARTS_WITH,
+            Self::NotEndsWith => Self::NOT_ENDS_WITH,
 
             Self::Between => Self::BETWEEN,
             Self::Lesser => Self::LESSER,
             Self::LesserOrEqual => Self::LESSER_OR_EQUAL,
             Self::Greater => Self::GREATER,
             Self::GreaterOrEqual => Self::GREATER_OR_EQUAL,
+
+            Self::NotBetween => Self::NOT_BETWEEN,
+
+            Self::IsNull => Self::IS_NULL,
+            Self::IsNotNull => Self::IS_NOT_NULL,
         };
         write!(f, "{}", s)
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// Whether a condition compares text case-sensitively
+///
+/// In HTTP form a case-insensitive condition adds `-ci` to its similarity,
+/// e.g. `name=equals-ci:john` or `name=contains-ci:jo`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Case {
+    #[default]
+    Sensitive,
+    Insensitive,
+}
+
+impl Case {
+    pub const INSENSITIVE_SUFFIX: &str = "-ci";
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum SortDirection {
+    #[default]
     Ascending,
     Descending,
 }
@@ -856,12 +1379,6 @@ impl SortDirection {
     pub const DESCENDING: &str = "desc";
 }
 
-impl Default for SortDirection {
-    fn default() -> Self {
-        Self::Ascending
-    }
-}
-
 impl FromStr for SortDirection {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self> {
@@ -883,12 +1400,53 @@ impl fmt::Display for SortDirection {
     }
 }
 
+#[cfg(feature = "http")]
 pub(crate) const QUESTION: char = '?';
 pub(crate) const AMPERSAND: char = '&';
 pub(crate) const EQUAL: char = '=';
 pub(crate) const COLON: char = ':';
 pub(crate) const COMMA: char = ',';
 pub(crate) const PERCENT: char = '%';
+pub(crate) const OPEN_PAREN: char = '(';
+pub(crate) const CLOSE_PAREN: char = ')';
+
+/// Key of each value inside a cursor token
+const CURSOR_VALUE: &str = "v";
+
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Unpadded base64url, as used in cursor tokens
+fn base64_url_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | (*byte as u32) << (16 - 8 * i)
+        });
+        // n bytes carry n + 1 sextets
+        for i in 0..=chunk.len() {
+            encoded.push(BASE64_URL[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    encoded
+}
+
+fn base64_url_decode(input: &str) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(input.len() / 4 * 3 + 2);
+    for chunk in input.as_bytes().chunks(4) {
+        if chunk.len() < 2 {
+            return None;
+        }
+        let mut group = 0u32;
+        for (i, c) in chunk.iter().enumerate() {
+            let sextet = BASE64_URL.iter().position(|b| b == c)? as u32;
+            group |= sextet << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            decoded.push((group >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(decoded)
+}
 
 /// URL decode a string, handling percent-encoded characters
 pub(crate) fn url_decode(input: &str) -> String {
@@ -0,0 +1,222 @@
+use crate::error::{Error, Result};
+use crate::query::{Fields, Order, Parameters, Query};
+use indexmap::IndexMap;
+
+/// Allowlist of the fields a query may filter, sort on and select.
+///
+/// Parameter and order keys are quoted as identifiers in the generated SQL,
+/// but without a schema they may name any column. A `Schema` declares the
+/// allowed keys up front; validation fails with `Error::UnknownField` for
+/// anything else. Each field also names the column or SQL expression its
+/// public name renders as.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schema(IndexMap<String, Field>);
+
+impl Schema {
+    pub fn new() -> Self {
+        Self(IndexMap::new())
+    }
+
+    pub fn inner(&self) -> &IndexMap<String, Field> {
+        &self.0
+    }
+
+    pub fn field(&mut self, field: Field) -> &mut Self {
+        self.0.insert(field.name().clone(), field);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Field> {
+        self.0.get(name)
+    }
+
+    /// Look up a field that may be used in a filter
+    pub fn filter_field(&self, name: &str) -> Result<&Field> {
+        match self.0.get(name) {
+            Some(field) if field.is_filterable() => Ok(field),
+            _ => Err(Error::UnknownField(name.into())),
+        }
+    }
+
+    /// Look up a field that may be used in an ORDER BY clause
+    pub fn sort_field(&self, name: &str) -> Result<&Field> {
+        match self.0.get(name) {
+            Some(field) if field.is_sortable() => Ok(field),
+            _ => Err(Error::UnknownField(name.into())),
+        }
+    }
+
+    /// Look up a field that may be returned in a projection
+    pub fn select_field(&self, name: &str) -> Result<&Field> {
+        match self.0.get(name) {
+            Some(field) if field.is_selectable() => Ok(field),
+            _ => Err(Error::UnknownField(name.into())),
+        }
+    }
+
+    pub fn validate(&self, query: &Query) -> Result<()> {
+        self.validate_parameters(&query.parameters)?;
+        self.validate_order(&query.order)?;
+        self.validate_fields(&query.fields)
+    }
+
+    pub fn validate_parameters(&self, parameters: &Parameters) -> Result<()> {
+        for key in parameters.fields() {
+            self.filter_field(key)?;
+        }
+        Ok(())
+    }
+
+    pub fn validate_order(&self, order: &Order) -> Result<()> {
+        for name in order.inner().keys() {
+            self.sort_field(name)?;
+        }
+        Ok(())
+    }
+
+    pub fn validate_fields(&self, fields: &Fields) -> Result<()> {
+        for name in fields.inner() {
+            self.select_field(name)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Schema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The type a field's values are converted to before they are bound
+///
+/// Without a declared type, values are guessed: integer, then real, then text.
+/// With one, a value that does not convert fails with `Error::InvalidValue`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldType {
+    Text,
+    Integer,
+    Real,
+    /// `true`/`false` or `1`/`0`, bound as 1 or 0
+    Bool,
+    /// Hex-encoded bytes, e.g. `cafe01`
+    Blob,
+    /// An ISO-8601 date or date-time, or a relative form such as `now-7d` or
+    /// `today`, stored in the given format
+    Date(DateFormat),
+}
+
+/// How a `FieldType::Date` column stores its values. Dates are in UTC.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateFormat {
+    /// Text `YYYY-MM-DD`
+    IsoDate,
+    /// Text `YYYY-MM-DD HH:MM:SS`, as produced by SQLite's `datetime()`
+    IsoDateTime,
+    /// Integer seconds since 1970-01-01
+    UnixSeconds,
+    /// Real days since noon on November 24, 4714 BC, as produced by `julianday()`
+    JulianDay,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    name: String,
+    column: String,
+    expression: bool,
+    filterable: bool,
+    sortable: bool,
+    selectable: bool,
+    kind: Option<FieldType>,
+}
+
+impl Field {
+    /// Create a field that can be filtered, sorted on and selected, rendered
+    /// as a column of the same name
+    pub fn new(name: String) -> Self {
+        Self {
+            column: name.clone(),
+            name,
+            expression: false,
+            filterable: true,
+            sortable: true,
+            selectable: true,
+            kind: None,
+        }
+    }
+
+    /// Render the field as a different column, quoted like any other, e.g.
+    /// `u.date_created` as `"u"."date_created"`
+    ///
+    /// # Examples
+    /// - `Field::new("createdAt".into()).column("u.date_created".into())`
+    pub fn column(mut self, column: String) -> Self {
+        self.column = column;
+        self.expression = false;
+        self
+    }
+
+    /// Render the field as an SQL expression, written into the SQL as given
+    ///
+    /// # Examples
+    /// - `Field::new("fullName".into()).expression("first_name || ' ' || last_name".into())`
+    pub fn expression(mut self, expression: String) -> Self {
+        self.column = expression;
+        self.expression = true;
+        self
+    }
+
+    /// Declare the type the field's values are bound as
+    ///
+    /// # Examples
+    /// - `Field::new("zip".into()).kind(FieldType::Text)` keeps `"01234"` as text
+    pub fn kind(mut self, kind: FieldType) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Whether `fields=` may ask for the field, e.g. `false` for a password hash
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn column_name(&self) -> &String {
+        &self.column
+    }
+
+    /// Whether `column_name()` is an expression rather than a column
+    pub fn is_expression(&self) -> bool {
+        self.expression
+    }
+
+    pub fn field_type(&self) -> Option<&FieldType> {
+        self.kind.as_ref()
+    }
+
+    pub fn is_filterable(&self) -> bool {
+        self.filterable
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        self.sortable
+    }
+
+    pub fn is_selectable(&self) -> bool {
+        self.selectable
+    }
+}
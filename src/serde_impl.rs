@@ -0,0 +1,440 @@
+//! Serde support, with the `serde` feature
+//!
+//! The JSON shape is stable: fields are only ever added, and every added
+//! field is optional on input. A query looks like:
+//!
+//! ```json
+//! {
+//!   "parameters": {
+//!     "conditions": {
+//!       "name": [{ "similarity": "contains", "values": ["john"] }],
+//!       "age": [
+//!         { "similarity": "greater", "values": ["18"] },
+//!         { "similarity": "lesser", "values": ["65"] }
+//!       ],
+//!       "email": [{ "similarity": "ends-with", "values": ["@example.com"], "case": "insensitive" }]
+//!     },
+//!     "groups": [
+//!       { "or": [
+//!         { "field": "status", "conditions": [{ "similarity": "equals", "values": ["draft"] }] },
+//!         { "not": { "field": "owner", "conditions": [{ "similarity": "is-null", "values": [] }] } }
+//!       ] }
+//!     ]
+//!   },
+//!   "order": [{ "field": "date_created", "direction": "desc" }],
+//!   "fields": ["id", "name"],
+//!   "limit": 50,
+//!   "offset": 0,
+//!   "cursor": null
+//! }
+//! ```
+//!
+//! - A field's conditions are a list of `Parameter`s combined with AND; in a
+//!   group, a field with several conditions is read as an `and` group of them
+//! - `similarity` and `direction` use the HTTP names, e.g. `"not-between"`
+//!   and `"asc"`; `case` is `"sensitive"` (the default, left out) or
+//!   `"insensitive"`
+//! - A group is `{"and": [...]}`, `{"or": [...]}`, `{"not": {...}}` or a
+//!   condition, `{"field": ..., "conditions": [...]}`
+//! - `cursor` is the list of the cursor's values, or `null`
+//! - Missing keys take `Query::new()`'s defaults, and unknown keys are
+//!   rejected
+
+use crate::query::{
+    Case, Cursor, Fields, Filter, Order, Parameter, Parameters, Query, Similarity, SortDirection,
+};
+use indexmap::IndexMap;
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+const SENSITIVE: &str = "sensitive";
+const INSENSITIVE: &str = "insensitive";
+
+/// Serialize through `Display` and deserialize through `FromStr`
+macro_rules! string_serde {
+    ($type:ty) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+string_serde!(Similarity);
+string_serde!(SortDirection);
+
+impl Serialize for Case {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            Case::Sensitive => SENSITIVE,
+            Case::Insensitive => INSENSITIVE,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Case {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            SENSITIVE => Ok(Case::Sensitive),
+            INSENSITIVE => Ok(Case::Insensitive),
+            other => Err(de::Error::unknown_variant(other, &[SENSITIVE, INSENSITIVE])),
+        }
+    }
+}
+
+/// A `Parameter` as read
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Condition {
+    similarity: Similarity,
+    #[serde(default)]
+    values: Vec<String>,
+    #[serde(default, skip_serializing_if = "is_sensitive")]
+    case: Case,
+}
+
+/// A `Parameter` as written, borrowing its values
+#[derive(Serialize)]
+struct ConditionRef<'a> {
+    similarity: &'a Similarity,
+    values: &'a [String],
+    #[serde(skip_serializing_if = "is_sensitive")]
+    case: Case,
+}
+
+fn is_sensitive(case: &Case) -> bool {
+    *case == Case::Sensitive
+}
+
+/// A parameter is a single condition
+impl Serialize for Parameter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ConditionRef {
+            similarity: self.similarity(),
+            values: self.values(),
+            case: *self.case(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Parameter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Condition {
+            similarity,
+            values,
+            case,
+        } = Condition::deserialize(deserializer)?;
+        let parameter = Parameter::init(similarity, values).with_case(case);
+        // The same check as `FromStr`, e.g. `is-null` takes no values
+        if !parameter.is_valid() {
+            return Err(de::Error::custom(format!(
+                "invalid values or case for '{}'",
+                parameter.similarity()
+            )));
+        }
+        Ok(parameter)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ParametersJson {
+    #[serde(default)]
+    conditions: IndexMap<String, Vec<Parameter>>,
+    #[serde(default)]
+    groups: Vec<Filter>,
+}
+
+#[derive(Serialize)]
+struct ParametersRef<'a> {
+    conditions: &'a IndexMap<String, Vec<Parameter>>,
+    groups: &'a [Filter],
+}
+
+impl Serialize for Parameters {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ParametersRef {
+            conditions: self.inner(),
+            groups: self.groups(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Parameters {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let ParametersJson { conditions, groups } = ParametersJson::deserialize(deserializer)?;
+        if let Some((field, _)) = conditions.iter().find(|(_, list)| list.is_empty()) {
+            return Err(de::Error::custom(format!("no conditions for '{field}'")));
+        }
+        let mut parameters = Parameters::new();
+        *parameters.inner_mut() = conditions;
+        *parameters.groups_mut() = groups;
+        Ok(parameters)
+    }
+}
+
+/// Exactly one of the keys is set
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FilterJson {
+    #[serde(default)]
+    field: Option<String>,
+    #[serde(default)]
+    conditions: Option<Vec<Parameter>>,
+    #[serde(default)]
+    and: Option<Vec<Filter>>,
+    #[serde(default)]
+    or: Option<Vec<Filter>>,
+    #[serde(default)]
+    not: Option<Box<Filter>>,
+}
+
+/// `FilterJson` as written, borrowing the filter
+#[derive(Default, Serialize)]
+struct FilterRef<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conditions: Option<[&'a Parameter; 1]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    and: Option<&'a [Filter]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    or: Option<&'a [Filter]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not: Option<&'a Filter>,
+}
+
+impl Serialize for Filter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let json = match self {
+            Filter::Condition(field, parameter) => FilterRef {
+                field: Some(field),
+                conditions: Some([parameter]),
+                ..FilterRef::default()
+            },
+            Filter::And(filters) => FilterRef {
+                and: Some(filters),
+                ..FilterRef::default()
+            },
+            Filter::Or(filters) => FilterRef {
+                or: Some(filters),
+                ..FilterRef::default()
+            },
+            Filter::Not(filter) => FilterRef {
+                not: Some(filter),
+                ..FilterRef::default()
+            },
+        };
+        json.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Filter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match FilterJson::deserialize(deserializer)? {
+            FilterJson {
+                field: Some(field),
+                conditions: Some(mut conditions),
+                and: None,
+                or: None,
+                not: None,
+            } => match conditions.len() {
+                0 => Err(de::Error::invalid_length(0, &"at least one condition")),
+                1 => Ok(Filter::Condition(field, conditions.remove(0))),
+                // Several conditions on the field are combined with AND
+                _ => Ok(Filter::And(
+                    conditions
+                        .into_iter()
+                        .map(|parameter| Filter::Condition(field.clone(), parameter))
+                        .collect(),
+                )),
+            },
+            FilterJson {
+                field: None,
+                conditions: None,
+                and: Some(filters),
+                or: None,
+                not: None,
+            } => Ok(Filter::And(filters)),
+            FilterJson {
+                field: None,
+                conditions: None,
+                and: None,
+                or: Some(filters),
+                not: None,
+            } => Ok(Filter::Or(filters)),
+            FilterJson {
+                field: None,
+                conditions: None,
+                and: None,
+                or: None,
+                not: Some(filter),
+            } => Ok(Filter::Not(filter)),
+            _ => Err(de::Error::custom(
+                "expected one of `field` with `conditions`, `and`, `or` or `not`",
+            )),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OrderFieldJson {
+    field: String,
+    #[serde(default)]
+    direction: SortDirection,
+}
+
+#[derive(Serialize)]
+struct OrderFieldRef<'a> {
+    field: &'a str,
+    direction: &'a SortDirection,
+}
+
+/// A list, since the order of the fields matters
+impl Serialize for Order {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.inner()
+                .iter()
+                .map(|(field, direction)| OrderFieldRef { field, direction }),
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for Order {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut order = Order::new();
+        for OrderFieldJson { field, direction } in Vec::deserialize(deserializer)? {
+            order.inner_mut().insert(field, direction);
+        }
+        Ok(order)
+    }
+}
+
+impl Serialize for Fields {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.inner())
+    }
+}
+
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut fields = Fields::new();
+        for name in Vec::<String>::deserialize(deserializer)? {
+            fields.add(name);
+        }
+        Ok(fields)
+    }
+}
+
+impl Serialize for Cursor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.values())
+    }
+}
+
+impl<'de> Deserialize<'de> for Cursor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Cursor::new(Vec::deserialize(deserializer)?))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct QueryJson {
+    parameters: Parameters,
+    order: Order,
+    fields: Fields,
+    limit: usize,
+    offset: usize,
+    cursor: Option<Cursor>,
+}
+
+impl Default for QueryJson {
+    fn default() -> Self {
+        Query::new().into()
+    }
+}
+
+impl From<Query> for QueryJson {
+    fn from(query: Query) -> Self {
+        let Query {
+            parameters,
+            order,
+            fields,
+            limit,
+            offset,
+            cursor,
+        } = query;
+        Self {
+            parameters,
+            order,
+            fields,
+            limit,
+            offset,
+            cursor,
+        }
+    }
+}
+
+/// `QueryJson` as written, borrowing the query
+#[derive(Serialize)]
+struct QueryRef<'a> {
+    parameters: &'a Parameters,
+    order: &'a Order,
+    fields: &'a Fields,
+    limit: usize,
+    offset: usize,
+    cursor: Option<&'a Cursor>,
+}
+
+impl Serialize for Query {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        QueryRef {
+            parameters: &self.parameters,
+            order: &self.order,
+            fields: &self.fields,
+            limit: self.limit,
+            offset: self.offset,
+            cursor: self.cursor.as_ref(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Query {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let QueryJson {
+            parameters,
+            order,
+            fields,
+            limit,
+            offset,
+            cursor,
+        } = QueryJson::deserialize(deserializer)?;
+        if let Some(cursor) = &cursor {
+            cursor.check(&order).map_err(de::Error::custom)?;
+        }
+        Ok(Query {
+            parameters,
+            order,
+            fields,
+            limit,
+            offset,
+            cursor,
+        })
+    }
+}
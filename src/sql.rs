@@ -1,5 +1,861 @@
-#[cfg(feature = "sql")]
+use crate::date;
+use crate::error::{Error, Result};
+use crate::query::{Case, Cursor, Filter, Parameter, Query, Similarity, SortDirection};
+use crate::schema::{DateFormat, Field, FieldType, Schema};
+use indexmap::IndexMap;
+
+mod delete;
+mod dialect;
+#[cfg(feature = "rusqlite")]
+mod fetch;
+mod memory;
+mod select;
+mod update;
+mod value;
+pub use delete::*;
+pub use dialect::*;
+#[cfg(feature = "rusqlite")]
+pub use fetch::*;
+pub use memory::*;
+pub use select::*;
+pub use update::*;
+pub use value::*;
+
+/// Value that binds as SQL NULL instead of text, unless the renderer's
+/// `null_sentinel` is turned off. Prefer the `is-null` and `is-not-null`
+/// similarities, which need no sentinel.
 pub const NULL: &str = "null";
 
-#[cfg(feature = "sql")]
-pub use rusqlite::types::{ToSql, Value};
+/// Escape character for the patterns built by contains, starts-with and
+/// ends-with, declared with `ESCAPE '\'` wherever they are compared
+pub const LIKE_ESCAPE: char = '\\';
+
+/// Marks a placeholder while a statement is rendered. `Renderer::finish`
+/// replaces each one with the dialect's placeholder once the statement is
+/// complete, so numbered placeholders count across all of its clauses.
+const PLACEHOLDER: char = '\u{0}';
+
+#[cfg(feature = "rusqlite")]
+pub use rusqlite::types::ToSql;
+
+#[cfg(feature = "rusqlite")]
+use rusqlite::Connection;
+#[cfg(feature = "rusqlite")]
+use rusqlite::functions::FunctionFlags;
+#[cfg(feature = "rusqlite")]
+use rusqlite::types::ValueRef;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Escape `%`, `_` and the escape character itself so a value matches only
+/// literally inside a LIKE pattern
+pub fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | LIKE_ESCAPE) {
+            escaped.push(LIKE_ESCAPE);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// How a `Renderer` compares text for case-insensitive conditions
+///
+/// SQLite's built-in `lower()` and `NOCASE` only fold ASCII letters. For
+/// accented and other non-ASCII text, register Unicode-aware versions with
+/// `register_unicode()` and render with `Fold::Function(UNICODE_LOWER)` or
+/// `Fold::Collate(UNICODE_NOCASE)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fold<'a> {
+    /// Apply a function to both sides, e.g. `lower(name) = lower(?)`
+    Function(&'a str),
+    /// Compare with a collation, e.g. `name COLLATE NOCASE = ?`. LIKE ignores
+    /// collations, so LIKE conditions use `lower()` instead.
+    Collate(&'a str),
+}
+
+impl Default for Fold<'_> {
+    fn default() -> Self {
+        Fold::Function("lower")
+    }
+}
+
+/// Name of the Unicode-aware lowercase function added by `register_unicode()`
+pub const UNICODE_LOWER: &str = "unicode_lower";
+
+/// Name of the Unicode-aware case-insensitive collation added by
+/// `register_unicode()`
+pub const UNICODE_NOCASE: &str = "unicode_nocase";
+
+/// Register `UNICODE_LOWER` and `UNICODE_NOCASE` on a connection, folding case
+/// with Rust's Unicode lowercase mapping rather than SQLite's ASCII-only one
+#[cfg(feature = "rusqlite")]
+pub fn register_unicode(connection: &Connection) -> Result<()> {
+    connection.create_scalar_function(
+        UNICODE_LOWER,
+        1,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            // Mirrors lower(): NULL stays NULL, anything else becomes text
+            Ok(match ctx.get_raw(0) {
+                ValueRef::Null => None,
+                ValueRef::Integer(i) => Some(i.to_string()),
+                ValueRef::Real(f) => Some(f.to_string()),
+                ValueRef::Text(bytes) | ValueRef::Blob(bytes) => {
+                    Some(String::from_utf8_lossy(bytes).to_lowercase())
+                }
+            })
+        },
+    )?;
+    connection.create_collation(UNICODE_NOCASE, |a, b| {
+        a.to_lowercase().cmp(&b.to_lowercase())
+    })?;
+    Ok(())
+}
+
+/// Renders a `Query` into SQL clauses and bound values.
+///
+/// Without a schema every parameter and order key is used verbatim as a column
+/// name. With a schema, keys are checked against it, rendered as the column
+/// each field maps to, and unknown ones are rejected with `Error::UnknownField`.
+/// SQL is written for SQLite unless another `Dialect` is set.
+#[derive(Clone, Copy, Debug)]
+pub struct Renderer<'a> {
+    query: &'a Query,
+    schema: Option<&'a Schema>,
+    null_sentinel: bool,
+    fold: Fold<'a>,
+    now: Option<i64>,
+    dialect: &'a dyn Dialect,
+    placeholders: Placeholders,
+    placeholder_offset: usize,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(query: &'a Query) -> Self {
+        Self {
+            query,
+            schema: None,
+            null_sentinel: true,
+            fold: Fold::default(),
+            now: None,
+            dialect: &Sqlite,
+            placeholders: Placeholders::Dialect,
+            placeholder_offset: 0,
+        }
+    }
+
+    pub fn schema(mut self, schema: &'a Schema) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Whether the `NULL` value (`"null"`) binds as SQL NULL. On by default;
+    /// turn it off to treat `"null"` as plain text like any other value.
+    pub fn null_sentinel(mut self, enabled: bool) -> Self {
+        self.null_sentinel = enabled;
+        self
+    }
+
+    /// How case-insensitive conditions (`equals-ci:` etc.) compare text
+    pub fn fold(mut self, fold: Fold<'a>) -> Self {
+        self.fold = fold;
+        self
+    }
+
+    /// Resolve relative dates such as `now-7d` against this time, in seconds
+    /// since 1970-01-01 UTC, instead of the system clock
+    pub fn now(mut self, now: i64) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// The database syntax to write, e.g. `&Postgres` for `$1` placeholders
+    pub fn dialect(mut self, dialect: &'a dyn Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Write numbered (`?1`) or named (`:p1`) placeholders instead of the
+    /// dialect's own
+    pub fn placeholders(mut self, placeholders: Placeholders) -> Self {
+        self.placeholders = placeholders;
+        self
+    }
+
+    /// Number placeholders after `offset` others, e.g. `?3` first with an
+    /// offset of 2. Applies to numbered, named and `$n` placeholders.
+    pub fn placeholder_offset(mut self, offset: usize) -> Self {
+        self.placeholder_offset = offset;
+        self
+    }
+
+    /// Key values by the placeholder they bind to, e.g. `:p1`, for any of the
+    /// value lists (`to_values()`, `parameter_values()`, `Select::to_values()`
+    /// ...) with the statement they were rendered with. Placeholders must be
+    /// distinct, so use `Placeholders::Numbered` or `Placeholders::Named`
+    /// unless the dialect numbers its own.
+    pub fn named_values(&self, values: Vec<Value>) -> IndexMap<String, Value> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| (self.placeholder(i + 1), value))
+            .collect()
+    }
+
+    /// Build a complete `SELECT` statement from `table`
+    pub fn select(self, table: &str) -> Select<'a> {
+        Select::new(self, table)
+    }
+
+    /// Build an `UPDATE` of `table` over the rows matching the filters
+    pub fn update(self, table: &str) -> Update<'a> {
+        Update::new(self, table)
+    }
+
+    /// Build a `DELETE` from `table` of the rows matching the filters
+    pub fn delete(self, table: &str) -> Delete<'a> {
+        Delete::new(self, table)
+    }
+
+    pub fn to_sql(&self) -> Result<String> {
+        let mut sql_parts = Vec::new();
+
+        // Build WHERE clause from parameters
+        if let Some(fragment) = self.where_fragment()? {
+            sql_parts.push(format!("WHERE {}", fragment.sql));
+        }
+
+        // Build ORDER BY clause from order
+        if let Some(order_clause) = self.order_clause()? {
+            sql_parts.push(format!("ORDER BY {}", order_clause));
+        }
+
+        // Add LIMIT and OFFSET; a cursor seeks in the WHERE clause instead
+        if self.query.cursor.is_some() {
+            sql_parts.push(format!("LIMIT {PLACEHOLDER}"));
+        } else {
+            sql_parts.push(format!("LIMIT {PLACEHOLDER} OFFSET {PLACEHOLDER}"));
+        }
+
+        Ok(self.finish(&sql_parts.join(" ")))
+    }
+
+    pub fn where_clause(&self) -> Result<Option<String>> {
+        Ok(self
+            .where_fragment()?
+            .map(|fragment| self.finish(&fragment.sql)))
+    }
+
+    pub fn order_clause(&self) -> Result<Option<String>> {
+        let mut order_parts = Vec::new();
+
+        for (name, direction) in self.query.order.inner() {
+            if !name.is_empty() {
+                let name = self.sort_column(name)?;
+                let direction = match direction {
+                    SortDirection::Ascending => "ASC",
+                    SortDirection::Descending => "DESC",
+                };
+                order_parts.push(format!("{} {}", name, direction));
+            }
+        }
+
+        if order_parts.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(order_parts.join(", ")))
+        }
+    }
+
+    /// The query's `fields` as a column list, or `None` to select everything.
+    /// Each field is quoted; a field the schema maps to another column or
+    /// expression is aliased back to its public name, e.g. `"u"."mail" AS "email"`.
+    pub fn column_list(&self) -> Result<Option<String>> {
+        let mut columns = Vec::new();
+
+        for name in self.query.fields.inner() {
+            let field = match self.schema {
+                Some(schema) => Some(schema.select_field(name)?),
+                None => None,
+            };
+            let column = self.column(name, field);
+            let quoted = self.quote(name);
+            if column == quoted {
+                columns.push(column);
+            } else {
+                columns.push(format!("{} AS {}", column, quoted));
+            }
+        }
+
+        if columns.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(columns.join(", ")))
+        }
+    }
+
+    /// `SELECT COUNT(*) FROM <table> WHERE ...`, counting every row that
+    /// matches the filters regardless of pagination or cursor
+    pub fn to_count_sql(&self, table: &str) -> Result<String> {
+        self.count_sql(&self.quote(table))
+    }
+
+    /// `to_count_sql()` over an already rendered FROM clause, e.g. a table
+    /// with its alias and joins
+    fn count_sql(&self, from: &str) -> Result<String> {
+        Ok(match self.filter_fragment()? {
+            Some(fragment) => self.finish(&format!(
+                "SELECT COUNT(*) FROM {} WHERE {}",
+                from, fragment.sql
+            )),
+            None => format!("SELECT COUNT(*) FROM {}", from),
+        })
+    }
+
+    /// Values for `to_count_sql()`: the filter values, without the cursor,
+    /// limit or offset
+    pub fn count_values(&self) -> Result<Vec<Value>> {
+        Ok(self
+            .filter_fragment()?
+            .map(|fragment| fragment.values)
+            .unwrap_or_default())
+    }
+
+    pub fn to_values(&self) -> Result<Vec<Value>> {
+        let mut sql_values = self.parameter_values()?;
+        sql_values.extend(self.pagination_values());
+        Ok(sql_values)
+    }
+
+    /// Get SQL values for parameters only (without limit and offset)
+    pub fn parameter_values(&self) -> Result<Vec<Value>> {
+        Ok(self
+            .where_fragment()?
+            .map(|fragment| fragment.values)
+            .unwrap_or_default())
+    }
+
+    /// Get SQL values for pagination (limit and offset, or only limit with a cursor)
+    pub fn pagination_values(&self) -> Vec<Value> {
+        // usize values past i64::MAX saturate rather than wrap negative
+        let mut values = vec![Value::Integer(
+            i64::try_from(self.query.limit).unwrap_or(i64::MAX),
+        )];
+        if self.query.cursor.is_none() {
+            values.push(Value::Integer(
+                i64::try_from(self.query.offset).unwrap_or(i64::MAX),
+            ));
+        }
+        values
+    }
+
+    /// Replace every placeholder marker with the placeholder to write,
+    /// numbered from 1 across the whole statement
+    fn finish(&self, sql: &str) -> String {
+        let mut finished = String::with_capacity(sql.len());
+        let mut index = 0;
+        for c in sql.chars() {
+            if c == PLACEHOLDER {
+                index += 1;
+                finished.push_str(&self.placeholder(index));
+            } else {
+                finished.push(c);
+            }
+        }
+        finished
+    }
+
+    /// The `index`th placeholder of a statement, from 1 and after the offset
+    fn placeholder(&self, index: usize) -> String {
+        let index = self.placeholder_offset + index;
+        match self.placeholders {
+            Placeholders::Dialect => self.dialect.placeholder(index),
+            Placeholders::Numbered => format!("?{}", index),
+            Placeholders::Named => format!(":p{}", index),
+        }
+    }
+
+    /// Quote a possibly dotted identifier in the renderer's dialect
+    fn quote(&self, identifier: &str) -> String {
+        self.dialect.quote_identifier(identifier)
+    }
+
+    /// What a field is written as: its quoted name or schema column, or the
+    /// schema's expression as given
+    fn column(&self, name: &str, field: Option<&Field>) -> String {
+        match field {
+            Some(field) if field.is_expression() => field.column_name().clone(),
+            Some(field) => self.quote(field.column_name()),
+            None => self.quote(name),
+        }
+    }
+
+    /// Render the WHERE conditions and the values they bind in one pass, so
+    /// the values always line up with the placeholders
+    fn where_fragment(&self) -> Result<Option<Fragment>> {
+        let mut parts = self.filter_parts()?;
+        if let Some(cursor) = &self.query.cursor {
+            parts.push(self.seek(cursor)?);
+        }
+        Ok(Fragment::join(parts, " AND "))
+    }
+
+    /// The filters as a single condition, without the cursor's seek predicate
+    fn filter_fragment(&self) -> Result<Option<Fragment>> {
+        Ok(Fragment::join(self.filter_parts()?, " AND "))
+    }
+
+    /// The conditions of the parameters and groups, to be ANDed, without the
+    /// cursor's seek predicate
+    fn filter_parts(&self) -> Result<Vec<Fragment>> {
+        let mut parts = Vec::new();
+
+        for (key, conditions) in self.query.parameters.inner() {
+            // Several conditions on the same field are combined with AND
+            parts.extend(self.conditions(key, conditions)?);
+        }
+
+        for filter in self.query.parameters.groups() {
+            parts.extend(self.filter(filter)?);
+        }
+
+        Ok(parts)
+    }
+
+    /// The keyset predicate selecting rows after the cursor in `Order`:
+    /// `(a, b) > (?, ?)` when every field sorts the same way, otherwise
+    /// `a > ? OR (a = ? AND b < ?)`
+    fn seek(&self, cursor: &Cursor) -> Result<Fragment> {
+        cursor.check(&self.query.order)?;
+        let order: Vec<(&String, &SortDirection)> = self
+            .query
+            .order
+            .inner()
+            .iter()
+            .filter(|(name, _)| !name.is_empty())
+            .collect();
+
+        let mut keys = Vec::new();
+        for ((name, direction), value) in order.iter().zip(cursor.values()) {
+            let (column, kind) = match self.schema {
+                Some(schema) => {
+                    let field = schema.sort_field(name)?;
+                    (self.column(name, Some(field)), field.field_type())
+                }
+                None => (self.column(name, None), None),
+            };
+            let operator = match direction {
+                SortDirection::Ascending => ">",
+                SortDirection::Descending => "<",
+            };
+            let value = self.value(name, kind, &Similarity::Equals, value)?;
+            keys.push((column, operator, value));
+        }
+
+        if keys.len() == 1 {
+            let (column, operator, value) = keys.remove(0);
+            return Ok(Fragment {
+                sql: format!("{} {} {PLACEHOLDER}", column, operator),
+                values: vec![value],
+                compound: false,
+            });
+        }
+
+        if keys.iter().all(|(_, operator, _)| *operator == keys[0].1) {
+            let columns: Vec<&str> = keys.iter().map(|(column, _, _)| column.as_str()).collect();
+            return Ok(Fragment {
+                sql: format!(
+                    "({}) {} ({})",
+                    columns.join(", "),
+                    keys[0].1,
+                    vec![PLACEHOLDER.to_string(); keys.len()].join(", ")
+                ),
+                values: keys.into_iter().map(|(_, _, value)| value).collect(),
+                compound: false,
+            });
+        }
+
+        // Mixed directions: past the cursor on the first key that differs
+        let mut branches = Vec::new();
+        for i in 0..keys.len() {
+            let mut sql = Vec::new();
+            let mut values = Vec::new();
+            for (column, _, value) in &keys[..i] {
+                sql.push(format!("{} = {PLACEHOLDER}", column));
+                values.push(value.clone());
+            }
+            let (column, operator, value) = &keys[i];
+            sql.push(format!("{} {} {PLACEHOLDER}", column, operator));
+            values.push(value.clone());
+
+            branches.push(Fragment {
+                compound: sql.len() > 1,
+                sql: sql.join(" AND "),
+                values,
+            });
+        }
+        Ok(Fragment::join(branches, " OR ").expect("at least two keys"))
+    }
+
+    fn current_time(&self) -> i64 {
+        self.now.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs() as i64)
+        })
+    }
+
+    fn filter(&self, filter: &'a Filter) -> Result<Option<Fragment>> {
+        let fragment = match filter {
+            Filter::Condition(key, param) => {
+                Fragment::join(self.conditions(key, std::slice::from_ref(param))?, " AND ")
+            }
+            Filter::And(filters) => Fragment::join(self.filters(filters)?, " AND "),
+            Filter::Or(filters) => Fragment::join(self.filters(filters)?, " OR "),
+            Filter::Not(filter) => self.filter(filter)?.map(|inner| Fragment {
+                sql: format!("NOT ({})", inner.sql),
+                values: inner.values,
+                compound: false,
+            }),
+        };
+        Ok(fragment)
+    }
+
+    fn filters(&self, filters: &'a [Filter]) -> Result<Vec<Fragment>> {
+        let mut parts = Vec::new();
+        for filter in filters {
+            parts.extend(self.filter(filter)?);
+        }
+        Ok(parts)
+    }
+
+    /// Render every condition on a field, converting the values it binds to
+    /// the field's declared type, if it has one
+    fn conditions(&self, key: &'a str, conditions: &'a [Parameter]) -> Result<Vec<Fragment>> {
+        let (column, kind) = match self.schema {
+            Some(schema) => {
+                let field = schema.filter_field(key)?;
+                (self.column(key, Some(field)), field.field_type())
+            }
+            None => (self.column(key, None), None),
+        };
+
+        let mut parts = Vec::new();
+        for condition in conditions {
+            if let Some((sql, bound)) = self.condition(&column, condition) {
+                let similarity = condition.similarity();
+                let values = bound
+                    .into_iter()
+                    .map(|value| self.value(key, kind, similarity, value))
+                    .collect::<Result<_>>()?;
+                parts.push(Fragment {
+                    sql,
+                    values,
+                    compound: false,
+                });
+            }
+        }
+        Ok(parts)
+    }
+
+    /// The SQL of a single condition and the raw values its placeholders bind
+    fn condition<'p>(
+        &self,
+        key: &str,
+        parameter: &'p Parameter,
+    ) -> Option<(String, Vec<&'p String>)> {
+        let similarity = parameter.similarity();
+        if !similarity.takes_values() {
+            let sql = match similarity {
+                Similarity::IsNotNull => format!("{} IS NOT NULL", key),
+                _ => format!("{} IS NULL", key),
+            };
+            return Some((sql, vec![]));
+        }
+
+        // Blank values are skipped, both as placeholders and as bound values
+        let values: Vec<&String> = parameter
+            .values()
+            .iter()
+            .filter(|v| !v.trim().is_empty())
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+
+        let case = parameter.case();
+        let (column, placeholder) = self.operands(key, case, false);
+        let comparison = |operator: &str| format!("{} {} {}", column, operator, placeholder);
+
+        let (sql, bound) = match similarity {
+            Similarity::Equals => self.membership(key, case, values, false),
+            Similarity::Contains | Similarity::StartsWith | Similarity::EndsWith => (
+                Self::any(
+                    &format!(
+                        "{} {}",
+                        self.like(key, case, false),
+                        self.dialect.like_escape()
+                    ),
+                    values.len(),
+                ),
+                values,
+            ),
+            Similarity::Like => (
+                Self::any(&self.like(key, case, false), values.len()),
+                values,
+            ),
+            Similarity::NotEquals => self.membership(key, case, values, true),
+            Similarity::NotContains | Similarity::NotStartsWith | Similarity::NotEndsWith => (
+                Self::all(
+                    &format!(
+                        "{} {}",
+                        self.like(key, case, true),
+                        self.dialect.like_escape()
+                    ),
+                    values.len(),
+                ),
+                values,
+            ),
+            Similarity::Between | Similarity::NotBetween => {
+                // Group values into pairs, ignoring any odd value
+                let pairs: Vec<&[&String]> = values.chunks_exact(2).collect();
+                if pairs.is_empty() {
+                    return None; // Skip invalid between conditions
+                }
+
+                // A value may fall in any of the ranges, or in none of them
+                let (operator, separator) = match similarity {
+                    Similarity::NotBetween => ("NOT BETWEEN", " AND "),
+                    _ => ("BETWEEN", " OR "),
+                };
+                let between = format!("{} AND {}", comparison(operator), placeholder);
+                let sql = if pairs.len() == 1 {
+                    between
+                } else {
+                    format!("({})", vec![between; pairs.len()].join(separator))
+                };
+                (sql, pairs.concat())
+            }
+            Similarity::Lesser => (Self::any(&comparison("<"), values.len()), values),
+            Similarity::LesserOrEqual => (Self::any(&comparison("<="), values.len()), values),
+            Similarity::Greater => (Self::any(&comparison(">"), values.len()), values),
+            Similarity::GreaterOrEqual => (Self::any(&comparison(">="), values.len()), values),
+            Similarity::IsNull | Similarity::IsNotNull => {
+                unreachable!("rendered without values above")
+            }
+        };
+
+        Some((sql, bound))
+    }
+
+    /// `key = ?` / `key IN (...)`, or their negation. NULL never matches inside
+    /// `IN`, so a sentinel null is compared separately with `IS` / `IS NOT`.
+    fn membership<'v>(
+        &self,
+        key: &str,
+        case: &Case,
+        values: Vec<&'v String>,
+        negated: bool,
+    ) -> (String, Vec<&'v String>) {
+        let (nulls, others): (Vec<&String>, Vec<&String>) = values
+            .into_iter()
+            .partition(|value| self.null_sentinel && *value == NULL);
+
+        let mut parts = Vec::new();
+        if !nulls.is_empty() {
+            let placeholder = PLACEHOLDER.to_string();
+            parts.push(self.dialect.null_safe_equals(key, &placeholder, negated));
+        }
+
+        let (column, placeholder) = self.operands(key, case, false);
+        match others.len() {
+            0 => {}
+            1 => {
+                let operator = if negated { "<>" } else { "=" };
+                parts.push(format!("{} {} {}", column, operator, placeholder));
+            }
+            count => {
+                let operator = if negated { "NOT IN" } else { "IN" };
+                let placeholders = vec![placeholder.as_str(); count].join(", ");
+                parts.push(format!("{} {} ({})", column, operator, placeholders));
+            }
+        }
+
+        let sql = if parts.len() == 1 {
+            parts.remove(0)
+        } else if negated {
+            format!("({})", parts.join(" AND "))
+        } else {
+            format!("({})", parts.join(" OR "))
+        };
+
+        // Repeated nulls collapse into the single IS / IS NOT comparison
+        let bound = nulls.into_iter().take(1).chain(others).collect();
+        (sql, bound)
+    }
+
+    /// `key [NOT] LIKE ?`, or the dialect's case-insensitive LIKE for a
+    /// case-insensitive condition if it has one
+    fn like(&self, key: &str, case: &Case, negated: bool) -> String {
+        let (operator, (column, placeholder)) = match (case, self.dialect.case_insensitive_like()) {
+            (Case::Insensitive, Some(operator)) => {
+                (operator, self.operands(key, &Case::Sensitive, true))
+            }
+            _ => ("LIKE", self.operands(key, case, true)),
+        };
+        let not = if negated { "NOT " } else { "" };
+        format!("{} {}{} {}", column, not, operator, placeholder)
+    }
+
+    /// The column and placeholder a comparison is written with, folded as the
+    /// renderer's `Fold` says for case-insensitive conditions
+    fn operands(&self, key: &str, case: &Case, pattern: bool) -> (String, String) {
+        match (case, self.fold) {
+            (Case::Sensitive, _) => (key.to_string(), PLACEHOLDER.to_string()),
+            (Case::Insensitive, Fold::Function(function)) => (
+                format!("{}({})", function, key),
+                format!("{}({PLACEHOLDER})", function),
+            ),
+            // LIKE ignores collations, so patterns fall back to lower()
+            (Case::Insensitive, Fold::Collate(_)) if pattern => {
+                (format!("lower({})", key), format!("lower({PLACEHOLDER})"))
+            }
+            (Case::Insensitive, Fold::Collate(collation)) => (
+                format!("{} COLLATE {}", key, collation),
+                PLACEHOLDER.to_string(),
+            ),
+        }
+    }
+
+    /// A single-placeholder comparison, or one per value ORed together
+    fn any(comparison: &str, count: usize) -> String {
+        if count == 1 {
+            comparison.to_string()
+        } else {
+            format!("({})", vec![comparison; count].join(" OR "))
+        }
+    }
+
+    /// A single-placeholder comparison, or one per value ANDed together
+    fn all(comparison: &str, count: usize) -> String {
+        if count == 1 {
+            comparison.to_string()
+        } else {
+            format!("({})", vec![comparison; count].join(" AND "))
+        }
+    }
+
+    fn value(
+        &self,
+        key: &str,
+        kind: Option<&FieldType>,
+        similarity: &Similarity,
+        value: &str,
+    ) -> Result<Value> {
+        if self.null_sentinel && value == NULL {
+            return Ok(Value::Null);
+        }
+
+        // LIKE patterns are always text, whatever the field's type
+        let value = match similarity {
+            Similarity::Contains | Similarity::NotContains => {
+                Value::Text(format!("%{}%", escape_like(value)))
+            }
+            Similarity::StartsWith | Similarity::NotStartsWith => {
+                Value::Text(format!("{}%", escape_like(value)))
+            }
+            Similarity::EndsWith | Similarity::NotEndsWith => {
+                Value::Text(format!("%{}", escape_like(value)))
+            }
+            Similarity::Like => Value::Text(value.to_string()),
+            _ => match kind {
+                Some(kind) => typed(kind, value, self.current_time())
+                    .ok_or_else(|| Error::InvalidValue(key.into(), value.into()))?,
+                None => {
+                    // Try to parse as integer first, then float, then text
+                    if let Ok(i) = value.parse::<i64>() {
+                        Value::Integer(i)
+                    } else if let Ok(f) = value.parse::<f64>() {
+                        Value::Real(f)
+                    } else {
+                        Value::Text(value.to_string())
+                    }
+                }
+            },
+        };
+        Ok(value)
+    }
+
+    fn sort_column(&self, name: &str) -> Result<String> {
+        match self.schema {
+            Some(schema) => Ok(self.column(name, Some(schema.sort_field(name)?))),
+            None => Ok(self.column(name, None)),
+        }
+    }
+}
+
+/// Convert a value to a declared field type, or `None` if it does not parse.
+/// `now` is the time relative dates are resolved against.
+fn typed(kind: &FieldType, value: &str, now: i64) -> Option<Value> {
+    match kind {
+        FieldType::Date(format) => date::parse(value, now).map(|seconds| match format {
+            DateFormat::IsoDate => Value::Text(date::format_date(seconds)),
+            DateFormat::IsoDateTime => Value::Text(date::format_date_time(seconds)),
+            DateFormat::UnixSeconds => Value::Integer(seconds),
+            DateFormat::JulianDay => Value::Real(date::julian_day(seconds)),
+        }),
+        FieldType::Text => Some(Value::Text(value.to_string())),
+        FieldType::Integer => value.parse().ok().map(Value::Integer),
+        FieldType::Real => value.parse().ok().map(Value::Real),
+        // SQLite stores booleans as 0 and 1
+        FieldType::Bool => match value.to_ascii_lowercase().as_str() {
+            "true" | "1" => Some(Value::Integer(1)),
+            "false" | "0" => Some(Value::Integer(0)),
+            _ => None,
+        },
+        FieldType::Blob => {
+            if !value.len().is_multiple_of(2) || !value.is_ascii() {
+                return None;
+            }
+            (0..value.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&value[i..i + 2], 16).ok())
+                .collect::<Option<Vec<u8>>>()
+                .map(Value::Blob)
+        }
+    }
+}
+
+/// A rendered SQL fragment and the values bound by its placeholders, in order
+struct Fragment {
+    sql: String,
+    values: Vec<Value>,
+    /// Whether `sql` joins several conditions with AND/OR at its top level
+    compound: bool,
+}
+
+impl Fragment {
+    fn join(parts: Vec<Fragment>, separator: &str) -> Option<Fragment> {
+        if parts.len() <= 1 {
+            return parts.into_iter().next();
+        }
+
+        let mut sql = Vec::new();
+        let mut values = Vec::new();
+        for part in parts {
+            if part.compound {
+                sql.push(format!("({})", part.sql));
+            } else {
+                sql.push(part.sql);
+            }
+            values.extend(part.values);
+        }
+
+        Some(Fragment {
+            sql: sql.join(separator),
+            values,
+            compound: true,
+        })
+    }
+}
@@ -0,0 +1,74 @@
+use super::{Fragment, Renderer, Value};
+use crate::error::{Error, Result};
+#[cfg(feature = "rusqlite")]
+use rusqlite::Connection;
+
+/// A `DELETE` statement for the rows matching a query's filters
+///
+/// Order, pagination, fields and cursor are ignored. A statement without a
+/// filter would delete every row, so rendering fails with
+/// `Error::MissingFilter` unless `allow_unfiltered(true)` is set.
+///
+/// # Examples
+/// - `query.delete("posts")` -> `DELETE FROM "posts" WHERE "status" = ? AND "created" < ?`
+#[derive(Clone, Debug)]
+pub struct Delete<'a> {
+    renderer: Renderer<'a>,
+    table: String,
+    unfiltered: bool,
+}
+
+impl<'a> Delete<'a> {
+    pub fn new(renderer: Renderer<'a>, table: &str) -> Self {
+        Self {
+            renderer,
+            table: table.to_string(),
+            unfiltered: false,
+        }
+    }
+
+    /// Allow the statement to render without a WHERE clause, deleting every row
+    pub fn allow_unfiltered(mut self, unfiltered: bool) -> Self {
+        self.unfiltered = unfiltered;
+        self
+    }
+
+    /// `DELETE FROM <table> WHERE ...`
+    pub fn to_sql(&self) -> Result<String> {
+        self.statement().map(|statement| statement.sql)
+    }
+
+    /// Values for `to_sql()`
+    pub fn to_values(&self) -> Result<Vec<Value>> {
+        self.statement().map(|statement| statement.values)
+    }
+
+    /// Run the statement, returning the number of rows deleted
+    #[cfg(feature = "rusqlite")]
+    pub fn execute(&self, connection: &Connection) -> Result<usize> {
+        let statement = self.statement()?;
+        let mut prepared = connection.prepare_cached(&statement.sql)?;
+        Ok(prepared.execute(rusqlite::params_from_iter(statement.values))?)
+    }
+
+    fn statement(&self) -> Result<Fragment> {
+        let mut sql = format!("DELETE FROM {}", self.renderer.quote(&self.table));
+        let mut values = Vec::new();
+
+        match self.renderer.filter_fragment()? {
+            Some(filter) => {
+                sql.push_str(" WHERE ");
+                sql.push_str(&filter.sql);
+                values = filter.values;
+            }
+            None if self.unfiltered => {}
+            None => return Err(Error::MissingFilter(self.table.clone())),
+        }
+
+        Ok(Fragment {
+            sql: self.renderer.finish(&sql),
+            values,
+            compound: false,
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use super::LIKE_ESCAPE;
+use std::fmt;
+
+/// The SQL syntax a `Renderer` writes: placeholders, identifier quoting and
+/// the few operators that differ between databases
+///
+/// Tables, aliases, columns and the keys in WHERE and ORDER BY are quoted by
+/// the dialect. Only a schema field declared with `Field::expression()` is
+/// written as given; write it in the target dialect.
+pub trait Dialect: fmt::Debug + Send + Sync {
+    /// The placeholder of the `index`th bound value of a statement, from 1
+    fn placeholder(&self, index: usize) -> String;
+
+    /// Quote a single identifier, doubling any embedded quote character
+    fn quote(&self, identifier: &str) -> String;
+
+    /// Quote a possibly dotted identifier part by part, leaving `*` bare
+    fn quote_identifier(&self, identifier: &str) -> String {
+        identifier
+            .split('.')
+            .map(|part| {
+                if part == "*" {
+                    part.to_string()
+                } else {
+                    self.quote(part)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+
+    /// A native case-insensitive LIKE operator such as `ILIKE`. Without one,
+    /// case-insensitive patterns compare folded text with LIKE.
+    fn case_insensitive_like(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The `ESCAPE` clause declaring `LIKE_ESCAPE` as the escape character
+    fn like_escape(&self) -> String {
+        format!("ESCAPE '{}'", LIKE_ESCAPE)
+    }
+
+    /// A comparison that also matches when both sides are NULL, used for the
+    /// `null` sentinel, e.g. `column IS ?`
+    fn null_safe_equals(&self, column: &str, placeholder: &str, negated: bool) -> String {
+        let operator = if negated { "IS NOT" } else { "IS" };
+        format!("{} {} {}", column, operator, placeholder)
+    }
+}
+
+/// How a `Renderer` writes placeholders
+///
+/// Numbered and named placeholders let a rendered fragment sit anywhere in
+/// hand-written SQL next to other bound parameters: start numbering after the
+/// caller's own with `Renderer::placeholder_offset()`, and bind by name with
+/// `Renderer::named_values()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Placeholders {
+    /// The dialect's own placeholders, e.g. `?` or `$1`
+    #[default]
+    Dialect,
+    /// SQLite's numbered placeholders: `?1`, `?2`, ...
+    Numbered,
+    /// Named placeholders: `:p1`, `:p2`, ...
+    Named,
+}
+
+/// SQLite: `?` placeholders and `"double-quoted"` identifiers
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sqlite;
+
+impl Dialect for Sqlite {
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    fn quote(&self, identifier: &str) -> String {
+        format!("\"{}\"", identifier.replace('"', "\"\""))
+    }
+}
+
+/// PostgreSQL: `$1..$n` placeholders, `"double-quoted"` identifiers, `ILIKE`
+/// and `IS [NOT] DISTINCT FROM`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Postgres;
+
+impl Dialect for Postgres {
+    fn placeholder(&self, index: usize) -> String {
+        format!("${}", index)
+    }
+
+    fn quote(&self, identifier: &str) -> String {
+        format!("\"{}\"", identifier.replace('"', "\"\""))
+    }
+
+    fn case_insensitive_like(&self) -> Option<&'static str> {
+        Some("ILIKE")
+    }
+
+    fn null_safe_equals(&self, column: &str, placeholder: &str, negated: bool) -> String {
+        let operator = if negated {
+            "IS DISTINCT FROM"
+        } else {
+            "IS NOT DISTINCT FROM"
+        };
+        format!("{} {} {}", column, operator, placeholder)
+    }
+}
+
+/// MySQL: `?` placeholders, `` `backtick-quoted` `` identifiers and `<=>`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MySql;
+
+impl Dialect for MySql {
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    fn quote(&self, identifier: &str) -> String {
+        format!("`{}`", identifier.replace('`', "``"))
+    }
+
+    // Backslash is an escape character in MySQL string literals
+    fn like_escape(&self) -> String {
+        format!("ESCAPE '{}{}'", LIKE_ESCAPE, LIKE_ESCAPE)
+    }
+
+    fn null_safe_equals(&self, column: &str, placeholder: &str, negated: bool) -> String {
+        if negated {
+            format!("NOT ({} <=> {})", column, placeholder)
+        } else {
+            format!("{} <=> {}", column, placeholder)
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::{NULL, Select};
+use crate::error::Result;
+use crate::query::{Cursor, Query};
+use rusqlite::{Connection, Row};
+
+/// One page of rows, the number of rows matching the filters, and the query
+/// for the page after it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page<T> {
+    pub rows: Vec<T>,
+    /// Every row that matches the filters, regardless of pagination
+    pub total: usize,
+    /// `None` on the last page. Continues with a cursor if the query used
+    /// one, and with the next offset otherwise.
+    pub next: Option<Query>,
+}
+
+impl<T> Page<T> {
+    pub fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+}
+
+impl Select<'_> {
+    /// Run the statement and map every row it returns
+    pub fn fetch<T, F>(&self, connection: &Connection, mut map: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&Row<'_>) -> rusqlite::Result<T>,
+    {
+        let mut statement = connection.prepare_cached(&self.to_sql()?)?;
+        let mut rows = statement.query(rusqlite::params_from_iter(self.to_values()?))?;
+
+        let mut mapped = Vec::new();
+        while let Some(row) = rows.next()? {
+            mapped.push(map(row)?);
+        }
+        Ok(mapped)
+    }
+
+    /// Run the statement and its count, and work out the next page
+    ///
+    /// One row past the limit is fetched to tell whether another page exists.
+    /// With a cursor, the next cursor is read from the last row's order
+    /// columns, so the order fields must be selected under their own names.
+    pub fn fetch_page<T, F>(&self, connection: &Connection, mut map: F) -> Result<Page<T>>
+    where
+        F: FnMut(&Row<'_>) -> rusqlite::Result<T>,
+    {
+        let query = self.query();
+        let mut peek = query.clone();
+        peek.limit = query.limit.saturating_add(1);
+        let select = self.with_query(&peek);
+
+        let mut statement = connection.prepare_cached(&select.to_sql()?)?;
+        let mut rows = statement.query(rusqlite::params_from_iter(select.to_values()?))?;
+
+        let mut mapped = Vec::new();
+        let mut cursor = None;
+        let mut more = false;
+        while let Some(row) = rows.next()? {
+            if mapped.len() == query.limit {
+                more = true;
+                break;
+            }
+            if query.cursor.is_some() {
+                cursor = Some(row_cursor(query, row)?);
+            }
+            mapped.push(map(row)?);
+        }
+
+        let total: i64 = connection
+            .prepare_cached(&self.to_count_sql()?)?
+            .query_row(rusqlite::params_from_iter(self.count_values()?), |row| {
+                row.get(0)
+            })?;
+
+        let next = if more && query.limit > 0 {
+            let mut next = query.clone();
+            match cursor {
+                Some(cursor) => next.cursor = Some(cursor),
+                None => next.offset = query.offset.saturating_add(query.limit),
+            }
+            Some(next)
+        } else {
+            None
+        };
+
+        Ok(Page {
+            rows: mapped,
+            total: total.max(0) as usize,
+            next,
+        })
+    }
+}
+
+/// The row's values of the query's order fields, by column name
+fn row_cursor(query: &Query, row: &Row<'_>) -> Result<Cursor> {
+    let mut values = Vec::new();
+    for name in query.order.inner().keys() {
+        let value = match row.get::<_, rusqlite::types::Value>(name.as_str())? {
+            rusqlite::types::Value::Null => NULL.to_string(),
+            rusqlite::types::Value::Integer(integer) => integer.to_string(),
+            rusqlite::types::Value::Real(real) => real.to_string(),
+            rusqlite::types::Value::Text(text) => text,
+            rusqlite::types::Value::Blob(bytes) => {
+                bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+            }
+        };
+        values.push(value);
+    }
+    Ok(Cursor::new(values))
+}
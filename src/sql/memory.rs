@@ -0,0 +1,495 @@
+use super::{Fold, LIKE_ESCAPE, NULL, Renderer, UNICODE_LOWER, UNICODE_NOCASE, Value};
+use crate::error::{Error, Result};
+use crate::query::{Case, Cursor, Filter, Parameter, Similarity, SortDirection};
+use crate::schema::FieldType;
+use indexmap::IndexMap;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// An item a `Query` can filter and sort in memory, such as a cached row or
+/// a test fixture
+///
+/// Fields are looked up by the names the query uses, not by the columns a
+/// schema maps them to. Return the value as SQLite would store it, e.g. a
+/// bool as `Value::Integer(1)`.
+pub trait Filterable {
+    /// The value of a field, or `None` if the item has no such field
+    fn field(&self, name: &str) -> Option<Value>;
+}
+
+impl Filterable for HashMap<String, Value> {
+    fn field(&self, name: &str) -> Option<Value> {
+        self.get(name).cloned()
+    }
+}
+
+impl Filterable for IndexMap<String, Value> {
+    fn field(&self, name: &str) -> Option<Value> {
+        self.get(name).cloned()
+    }
+}
+
+/// SQL's three-valued logic: `None` is unknown, the result of comparing with
+/// NULL. A WHERE clause keeps only rows where it is `Some(true)`.
+type Truth = Option<bool>;
+
+/// Evaluates the query in memory with the semantics SQLite gives the SQL the
+/// renderer writes: NULL comparisons are unknown, `LIKE` ignores ASCII case,
+/// and text compares with numbers as it would in a column of the item's type.
+impl Renderer<'_> {
+    /// Whether the WHERE clause, including a cursor's seek, keeps `item`
+    pub fn matches<T: Filterable>(&self, item: &T) -> Result<bool> {
+        let mut truth = Some(true);
+        for (key, conditions) in self.query.parameters.inner() {
+            for condition in self.conditions_truth(item, key, conditions)? {
+                truth = and(truth, condition);
+            }
+        }
+        for filter in self.query.parameters.groups() {
+            if let Some(condition) = self.filter_truth(item, filter)? {
+                truth = and(truth, condition);
+            }
+        }
+        if let Some(cursor) = &self.query.cursor {
+            truth = and(truth, self.seek_truth(item, cursor)?);
+        }
+        Ok(truth == Some(true))
+    }
+
+    /// Sort as `ORDER BY` does: NULL first, then numbers, text and blobs,
+    /// reversed for descending fields. Ties keep their order.
+    pub fn sort<T: Filterable>(&self, items: &mut [T]) -> Result<()> {
+        let mut order = Vec::new();
+        for (name, direction) in self.query.order.inner() {
+            if name.is_empty() {
+                continue;
+            }
+            self.sort_column(name)?;
+            order.push((name.as_str(), direction));
+        }
+        for item in items.iter() {
+            for (name, _) in &order {
+                field(item, name)?;
+            }
+        }
+
+        items.sort_by(|a, b| {
+            for (name, direction) in &order {
+                let a = a.field(name).unwrap_or(Value::Null);
+                let b = b.field(name).unwrap_or(Value::Null);
+                let ordering = match direction {
+                    SortDirection::Ascending => sort_order(&a, &b),
+                    SortDirection::Descending => sort_order(&b, &a),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        });
+        Ok(())
+    }
+
+    /// Apply `LIMIT` and, without a cursor, `OFFSET`
+    pub fn paginate<T>(&self, items: Vec<T>) -> Vec<T> {
+        let offset = match self.query.cursor {
+            Some(_) => 0,
+            None => self.query.offset,
+        };
+        items
+            .into_iter()
+            .skip(offset)
+            .take(self.query.limit)
+            .collect()
+    }
+
+    /// Filter, sort and paginate items, returning what `SELECT` would
+    pub fn apply<T, I>(&self, items: I) -> Result<Vec<T>>
+    where
+        T: Filterable,
+        I: IntoIterator<Item = T>,
+    {
+        let mut kept = Vec::new();
+        for item in items {
+            if self.matches(&item)? {
+                kept.push(item);
+            }
+        }
+        self.sort(&mut kept)?;
+        Ok(self.paginate(kept))
+    }
+
+    /// `None` for a group without any condition, which the SQL leaves out
+    fn filter_truth<T: Filterable>(&self, item: &T, filter: &Filter) -> Result<Option<Truth>> {
+        let truth = match filter {
+            Filter::Condition(key, param) => self
+                .conditions_truth(item, key, std::slice::from_ref(param))?
+                .into_iter()
+                .reduce(and),
+            Filter::And(filters) => self.filters_truth(item, filters)?.into_iter().reduce(and),
+            Filter::Or(filters) => self.filters_truth(item, filters)?.into_iter().reduce(or),
+            Filter::Not(filter) => self.filter_truth(item, filter)?.map(not),
+        };
+        Ok(truth)
+    }
+
+    fn filters_truth<T: Filterable>(&self, item: &T, filters: &[Filter]) -> Result<Vec<Truth>> {
+        let mut truths = Vec::new();
+        for filter in filters {
+            truths.extend(self.filter_truth(item, filter)?);
+        }
+        Ok(truths)
+    }
+
+    /// Every condition on a field that renders, mirroring `conditions`
+    fn conditions_truth<T: Filterable>(
+        &self,
+        item: &T,
+        key: &str,
+        conditions: &[Parameter],
+    ) -> Result<Vec<Truth>> {
+        let kind = match self.schema {
+            Some(schema) => schema.filter_field(key)?.field_type(),
+            None => None,
+        };
+        let actual = field(item, key)?;
+
+        let mut truths = Vec::new();
+        for condition in conditions {
+            truths.extend(self.condition_truth(&actual, key, kind, condition)?);
+        }
+        Ok(truths)
+    }
+
+    /// `None` for a condition the SQL skips, e.g. one with only blank values
+    fn condition_truth(
+        &self,
+        actual: &Value,
+        key: &str,
+        kind: Option<&FieldType>,
+        parameter: &Parameter,
+    ) -> Result<Option<Truth>> {
+        let similarity = parameter.similarity();
+        match similarity {
+            Similarity::IsNull => return Ok(Some(Some(*actual == Value::Null))),
+            Similarity::IsNotNull => return Ok(Some(Some(*actual != Value::Null))),
+            _ => {}
+        }
+
+        let raw: Vec<&String> = parameter
+            .values()
+            .iter()
+            .filter(|v| !v.trim().is_empty())
+            .collect();
+        if raw.is_empty() {
+            return Ok(None);
+        }
+        let values = raw
+            .iter()
+            .map(|value| self.value(key, kind, similarity, value))
+            .collect::<Result<Vec<Value>>>()?;
+
+        let case = parameter.case();
+        let compare = |value: &Value, accept: fn(Ordering) -> bool| -> Truth {
+            self.ordering(actual, value, case).map(accept)
+        };
+        let like = |value: &Value| self.like_truth(actual, value, case, true);
+
+        let truth = match similarity {
+            Similarity::Equals | Similarity::NotEquals => {
+                let negated = *similarity == Similarity::NotEquals;
+                let mut nulls = false;
+                let mut others = Vec::new();
+                for (raw, value) in raw.into_iter().zip(&values) {
+                    if self.null_sentinel && raw == NULL {
+                        nulls = true;
+                    } else {
+                        others.push(value);
+                    }
+                }
+
+                // `IS ?` / `IS NOT ?` never yield unknown
+                let mut parts = Vec::new();
+                if nulls {
+                    parts.push(Some((*actual == Value::Null) != negated));
+                }
+                if !others.is_empty() {
+                    let within = others
+                        .into_iter()
+                        .map(|value| compare(value, Ordering::is_eq))
+                        .fold(Some(false), or);
+                    parts.push(if negated { not(within) } else { within });
+                }
+                match negated {
+                    true => parts.into_iter().fold(Some(true), and),
+                    false => parts.into_iter().fold(Some(false), or),
+                }
+            }
+            Similarity::Contains | Similarity::StartsWith | Similarity::EndsWith => {
+                values.iter().map(like).fold(Some(false), or)
+            }
+            Similarity::Like => values
+                .iter()
+                .map(|value| self.like_truth(actual, value, case, false))
+                .fold(Some(false), or),
+            Similarity::NotContains | Similarity::NotStartsWith | Similarity::NotEndsWith => values
+                .iter()
+                .map(|value| not(like(value)))
+                .fold(Some(true), and),
+            Similarity::Between | Similarity::NotBetween => {
+                let ranges: Vec<Truth> = values
+                    .chunks_exact(2)
+                    .map(|pair| {
+                        and(
+                            compare(&pair[0], Ordering::is_ge),
+                            compare(&pair[1], Ordering::is_le),
+                        )
+                    })
+                    .collect();
+                if ranges.is_empty() {
+                    return Ok(None);
+                }
+                match similarity {
+                    Similarity::NotBetween => ranges.into_iter().map(not).fold(Some(true), and),
+                    _ => ranges.into_iter().fold(Some(false), or),
+                }
+            }
+            Similarity::Lesser => any(&values, |value| compare(value, Ordering::is_lt)),
+            Similarity::LesserOrEqual => any(&values, |value| compare(value, Ordering::is_le)),
+            Similarity::Greater => any(&values, |value| compare(value, Ordering::is_gt)),
+            Similarity::GreaterOrEqual => any(&values, |value| compare(value, Ordering::is_ge)),
+            Similarity::IsNull | Similarity::IsNotNull => unreachable!("evaluated above"),
+        };
+        Ok(Some(truth))
+    }
+
+    /// Whether the item comes after the cursor in `Order`, mirroring `seek`
+    fn seek_truth<T: Filterable>(&self, item: &T, cursor: &Cursor) -> Result<Truth> {
+        cursor.check(&self.query.order)?;
+        let order = self
+            .query
+            .order
+            .inner()
+            .iter()
+            .filter(|(name, _)| !name.is_empty());
+
+        let mut keys = Vec::new();
+        for ((name, direction), value) in order.zip(cursor.values()) {
+            let kind = match self.schema {
+                Some(schema) => schema.sort_field(name)?.field_type(),
+                None => None,
+            };
+            let value = self.value(name, kind, &Similarity::Equals, value)?;
+            keys.push((field(item, name)?, direction, value));
+        }
+
+        // Past the cursor on the first key that differs
+        let mut truth = Some(false);
+        let mut equal = Some(true);
+        for (actual, direction, value) in &keys {
+            let ordering = self.ordering(actual, value, &Case::Sensitive);
+            let past = match direction {
+                SortDirection::Ascending => ordering.map(Ordering::is_gt),
+                SortDirection::Descending => ordering.map(Ordering::is_lt),
+            };
+            truth = or(truth, and(equal, past));
+            equal = and(equal, ordering.map(Ordering::is_eq));
+        }
+        Ok(truth)
+    }
+
+    /// Compare a field with a bound value, or `None` if either is NULL
+    fn ordering(&self, actual: &Value, value: &Value, case: &Case) -> Option<Ordering> {
+        match (case, self.fold) {
+            (Case::Sensitive, _) => compare(actual, value),
+            // lower() turns anything but NULL into text
+            (Case::Insensitive, Fold::Function(function)) => {
+                let folded = |value: &Value| text(value).map(|text| fold(function, &text));
+                Some(folded(actual)?.cmp(&folded(value)?))
+            }
+            (Case::Insensitive, Fold::Collate(collation)) => match (actual, value) {
+                (Value::Text(a), Value::Text(b)) => {
+                    Some(fold(collation, a).cmp(&fold(collation, b)))
+                }
+                _ => compare(actual, value),
+            },
+        }
+    }
+
+    /// `actual LIKE pattern`, folded as `operands` folds patterns
+    fn like_truth(&self, actual: &Value, pattern: &Value, case: &Case, escape: bool) -> Truth {
+        let (actual, pattern) = (text(actual)?, text(pattern)?);
+        let function = match self.fold {
+            Fold::Function(function) => function,
+            Fold::Collate(_) => "lower",
+        };
+        Some(match case {
+            Case::Sensitive => like(&actual, &pattern, escape),
+            Case::Insensitive => like(&fold(function, &actual), &fold(function, &pattern), escape),
+        })
+    }
+}
+
+fn field<T: Filterable>(item: &T, name: &str) -> Result<Value> {
+    item.field(name)
+        .ok_or_else(|| Error::UnknownField(name.into()))
+}
+
+fn any(values: &[Value], truth: impl Fn(&Value) -> Truth) -> Truth {
+    values.iter().map(truth).fold(Some(false), or)
+}
+
+fn and(a: Truth, b: Truth) -> Truth {
+    match (a, b) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+fn or(a: Truth, b: Truth) -> Truth {
+    match (a, b) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+fn not(a: Truth) -> Truth {
+    a.map(|a| !a)
+}
+
+/// Fold case the way the named function or collation does: Rust's Unicode
+/// mapping for the `register_unicode()` ones, ASCII for SQLite's own
+fn fold(name: &str, text: &str) -> String {
+    if name == UNICODE_LOWER || name == UNICODE_NOCASE {
+        text.to_lowercase()
+    } else {
+        text.to_ascii_lowercase()
+    }
+}
+
+/// A value as SQLite converts it to text, or `None` for NULL
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::Integer(integer) => Some(integer.to_string()),
+        // SQLite keeps the decimal point of whole reals, e.g. 30.0
+        Value::Real(real) if real.fract() == 0.0 && real.abs() < 1e15 => {
+            Some(format!("{:.1}", real))
+        }
+        Value::Real(real) => Some(real.to_string()),
+        Value::Text(text) => Some(text.clone()),
+        Value::Blob(bytes) => Some(String::from_utf8_lossy(bytes).into_owned()),
+    }
+}
+
+/// Compare a field with a bound value as SQLite compares a column of the
+/// field's type: text against a number compares as text, a number against
+/// numeric text compares as numbers. `None` if either is NULL.
+fn compare(actual: &Value, value: &Value) -> Option<Ordering> {
+    match (actual, value) {
+        (Value::Null, _) | (_, Value::Null) => None,
+        (Value::Text(_), Value::Integer(_) | Value::Real(_)) => {
+            Some(text(actual)?.cmp(&text(value)?))
+        }
+        (Value::Integer(_) | Value::Real(_), Value::Text(text)) => match numeric(text) {
+            Some(number) => Some(sort_order(actual, &number)),
+            None => Some(Ordering::Less),
+        },
+        _ => Some(sort_order(actual, value)),
+    }
+}
+
+fn numeric(text: &str) -> Option<Value> {
+    let text = text.trim();
+    if let Ok(integer) = text.parse::<i64>() {
+        Some(Value::Integer(integer))
+    } else {
+        text.parse::<f64>().ok().map(Value::Real)
+    }
+}
+
+/// SQLite's ordering of values: NULL, then numbers, text and blobs
+fn sort_order(a: &Value, b: &Value) -> Ordering {
+    fn class(value: &Value) -> u8 {
+        match value {
+            Value::Null => 0,
+            Value::Integer(_) | Value::Real(_) => 1,
+            Value::Text(_) => 2,
+            Value::Blob(_) => 3,
+        }
+    }
+
+    match (a, b) {
+        (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+        (Value::Integer(a), Value::Real(b)) => (*a as f64).total_cmp(b),
+        (Value::Real(a), Value::Integer(b)) => a.total_cmp(&(*b as f64)),
+        (Value::Real(a), Value::Real(b)) => a.total_cmp(b),
+        (Value::Text(a), Value::Text(b)) => a.cmp(b),
+        (Value::Blob(a), Value::Blob(b)) => a.cmp(b),
+        _ => class(a).cmp(&class(b)),
+    }
+}
+
+enum Token {
+    /// `%`
+    Any,
+    /// `_`
+    One,
+    Char(char),
+}
+
+/// SQLite's LIKE: `%` matches any run of characters, `_` any single one, and
+/// ASCII letters match regardless of case. With `escape`, `LIKE_ESCAPE`
+/// makes the next character literal.
+fn like(text: &str, pattern: &str, escape: bool) -> bool {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '%' => Token::Any,
+            '_' => Token::One,
+            LIKE_ESCAPE if escape => match chars.next() {
+                Some(c) => Token::Char(c),
+                // A trailing escape is an error in SQLite; match nothing
+                None => return false,
+            },
+            c => Token::Char(c),
+        });
+    }
+
+    let text: Vec<char> = text.chars().collect();
+    let (mut t, mut p) = (0, 0);
+    // The last `%` seen and the text position it is currently matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match tokens.get(p) {
+            Some(Token::Any) => {
+                backtrack = Some((p, t));
+                p += 1;
+                continue;
+            }
+            Some(Token::One) => {
+                t += 1;
+                p += 1;
+                continue;
+            }
+            Some(Token::Char(c)) if c.eq_ignore_ascii_case(&text[t]) => {
+                t += 1;
+                p += 1;
+                continue;
+            }
+            _ => {}
+        }
+        match backtrack {
+            Some((star, matched)) => {
+                backtrack = Some((star, matched + 1));
+                p = star + 1;
+                t = matched + 1;
+            }
+            None => return false,
+        }
+    }
+    tokens[p..].iter().all(|token| matches!(token, Token::Any))
+}
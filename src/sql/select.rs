@@ -0,0 +1,207 @@
+use super::{Dialect, Renderer, Sqlite, Value};
+use crate::error::Result;
+
+/// A complete `SELECT` statement built around a `Renderer`
+///
+/// The table, selected columns and joined tables are quoted as identifiers;
+/// the WHERE, ORDER BY and pagination clauses come from the renderer. Without
+/// explicit columns, the query's `fields` are selected, or `*` if it has none.
+///
+/// # Examples
+/// - `query.select("users")` -> `SELECT * FROM "users" WHERE ... LIMIT ? OFFSET ?`
+/// - `query.select("users").alias("u").columns(&["u.id", "p.title"]).join(Join::left("posts", "p.user_id = u.id").alias("p"))`
+///   -> `SELECT "u"."id", "p"."title" FROM "users" AS "u" LEFT JOIN "posts" AS "p" ON p.user_id = u.id WHERE ...`
+#[derive(Clone, Debug)]
+pub struct Select<'a> {
+    renderer: Renderer<'a>,
+    table: String,
+    alias: Option<String>,
+    columns: Vec<String>,
+    joins: Vec<Join>,
+}
+
+impl<'a> Select<'a> {
+    pub fn new(renderer: Renderer<'a>, table: &str) -> Self {
+        Self {
+            renderer,
+            table: table.to_string(),
+            alias: None,
+            columns: Vec::new(),
+            joins: Vec::new(),
+        }
+    }
+
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    /// Add a column to select; explicit columns take precedence over the
+    /// query's `fields`
+    pub fn column(mut self, column: &str) -> Self {
+        self.columns.push(column.to_string());
+        self
+    }
+
+    pub fn columns(mut self, columns: &[&str]) -> Self {
+        self.columns
+            .extend(columns.iter().map(|column| column.to_string()));
+        self
+    }
+
+    pub fn join(mut self, join: Join) -> Self {
+        self.joins.push(join);
+        self
+    }
+
+    /// `SELECT <columns> FROM <table> <joins> WHERE ... ORDER BY ... LIMIT ...`
+    pub fn to_sql(&self) -> Result<String> {
+        let columns = if self.columns.is_empty() {
+            self.renderer
+                .column_list()?
+                .unwrap_or_else(|| "*".to_string())
+        } else {
+            self.columns
+                .iter()
+                .map(|column| self.renderer.quote(column))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        let mut sql = format!("SELECT {} FROM {}", columns, self.from());
+        let tail = self.renderer.to_sql()?;
+        if !tail.is_empty() {
+            sql.push(' ');
+            sql.push_str(&tail);
+        }
+        Ok(sql)
+    }
+
+    /// Values for `to_sql()`, in placeholder order
+    pub fn to_values(&self) -> Result<Vec<Value>> {
+        self.renderer.to_values()
+    }
+
+    /// `SELECT COUNT(*) FROM <table> <joins> WHERE ...`, without pagination
+    pub fn to_count_sql(&self) -> Result<String> {
+        self.renderer.count_sql(&self.from())
+    }
+
+    /// Values for `to_count_sql()`
+    pub fn count_values(&self) -> Result<Vec<Value>> {
+        self.renderer.count_values()
+    }
+
+    #[cfg(feature = "rusqlite")]
+    pub(super) fn query(&self) -> &'a crate::Query {
+        self.renderer.query
+    }
+
+    /// The same statement rendered for another query, e.g. with a different
+    /// limit
+    #[cfg(feature = "rusqlite")]
+    pub(super) fn with_query<'b>(&self, query: &'b crate::Query) -> Select<'b>
+    where
+        'a: 'b,
+    {
+        Select {
+            renderer: Renderer {
+                query,
+                ..self.renderer
+            },
+            table: self.table.clone(),
+            alias: self.alias.clone(),
+            columns: self.columns.clone(),
+            joins: self.joins.clone(),
+        }
+    }
+
+    /// The table, its alias and every join
+    fn from(&self) -> String {
+        let dialect = self.renderer.dialect;
+        let mut from = table_reference(dialect, &self.table, self.alias.as_deref());
+        for join in &self.joins {
+            from.push(' ');
+            from.push_str(&join.render(dialect));
+        }
+        from
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JoinKind {
+    Inner,
+    Left,
+}
+
+/// A table joined into a `Select`. The table and alias are quoted; the `ON`
+/// condition is trusted SQL and rendered as written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Join {
+    kind: JoinKind,
+    table: String,
+    alias: Option<String>,
+    on: String,
+}
+
+impl Join {
+    pub fn new(kind: JoinKind, table: &str, on: &str) -> Self {
+        Self {
+            kind,
+            table: table.to_string(),
+            alias: None,
+            on: on.to_string(),
+        }
+    }
+
+    pub fn inner(table: &str, on: &str) -> Self {
+        Self::new(JoinKind::Inner, table, on)
+    }
+
+    pub fn left(table: &str, on: &str) -> Self {
+        Self::new(JoinKind::Left, table, on)
+    }
+
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    /// `<kind> JOIN <table> [AS <alias>] ON <condition>` in `dialect`
+    pub fn render(&self, dialect: &dyn Dialect) -> String {
+        let kind = match self.kind {
+            JoinKind::Inner => "INNER JOIN",
+            JoinKind::Left => "LEFT JOIN",
+        };
+        format!(
+            "{} {} ON {}",
+            kind,
+            table_reference(dialect, &self.table, self.alias.as_deref()),
+            self.on
+        )
+    }
+}
+
+impl std::fmt::Display for Join {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(&Sqlite))
+    }
+}
+
+/// Quote an identifier for SQLite, e.g. `users` → `"users"`. Each part of a
+/// dotted name is quoted separately (`u.name` → `"u"."name"`), a `*` part is
+/// left bare, and embedded quotes are doubled.
+pub fn quote_identifier(identifier: &str) -> String {
+    Sqlite.quote_identifier(identifier)
+}
+
+fn table_reference(dialect: &dyn Dialect, table: &str, alias: Option<&str>) -> String {
+    match alias {
+        Some(alias) => format!(
+            "{} AS {}",
+            dialect.quote_identifier(table),
+            dialect.quote_identifier(alias)
+        ),
+        None => dialect.quote_identifier(table),
+    }
+}
@@ -0,0 +1,113 @@
+use super::{Fragment, PLACEHOLDER, Renderer, Value};
+use crate::error::{Error, Result};
+#[cfg(feature = "rusqlite")]
+use rusqlite::Connection;
+
+/// An `UPDATE` statement over the rows matching a query's filters
+///
+/// Order, pagination, fields and cursor are ignored. A statement without a
+/// filter would update every row, so rendering fails with
+/// `Error::MissingFilter` unless `allow_unfiltered(true)` is set.
+///
+/// # Examples
+/// - `query.update("posts").set("status", Value::Text("archived".into()))`
+///   -> `UPDATE "posts" SET "status" = ? WHERE "status" = ? AND "created" < ?`
+#[derive(Clone, Debug)]
+pub struct Update<'a> {
+    renderer: Renderer<'a>,
+    table: String,
+    assignments: Vec<(String, Value)>,
+    unfiltered: bool,
+}
+
+impl<'a> Update<'a> {
+    pub fn new(renderer: Renderer<'a>, table: &str) -> Self {
+        Self {
+            renderer,
+            table: table.to_string(),
+            assignments: Vec::new(),
+            unfiltered: false,
+        }
+    }
+
+    /// Set a field to a bound value
+    ///
+    /// With a schema, the field must be filterable and renders as its mapped
+    /// column, like the WHERE clause; without one it is a quoted column name.
+    pub fn set(mut self, column: &str, value: Value) -> Self {
+        self.assignments.push((column.to_string(), value));
+        self
+    }
+
+    /// Allow the statement to render without a WHERE clause, updating every row
+    pub fn allow_unfiltered(mut self, unfiltered: bool) -> Self {
+        self.unfiltered = unfiltered;
+        self
+    }
+
+    /// `UPDATE <table> SET <column> = ?, ... WHERE ...`
+    pub fn to_sql(&self) -> Result<String> {
+        self.statement().map(|statement| statement.sql)
+    }
+
+    /// Values for `to_sql()`: the assignments, then the filters
+    pub fn to_values(&self) -> Result<Vec<Value>> {
+        self.statement().map(|statement| statement.values)
+    }
+
+    /// Run the statement, returning the number of rows updated
+    #[cfg(feature = "rusqlite")]
+    pub fn execute(&self, connection: &Connection) -> Result<usize> {
+        let statement = self.statement()?;
+        let mut prepared = connection.prepare_cached(&statement.sql)?;
+        Ok(prepared.execute(rusqlite::params_from_iter(statement.values))?)
+    }
+
+    fn statement(&self) -> Result<Fragment> {
+        if self.assignments.is_empty() {
+            return Err(Error::MissingAssignments(self.table.clone()));
+        }
+
+        let assignments = self
+            .assignments
+            .iter()
+            .map(|(column, _)| Ok(format!("{} = {PLACEHOLDER}", self.column(column)?)))
+            .collect::<Result<Vec<String>>>()?
+            .join(", ");
+        let mut sql = format!(
+            "UPDATE {} SET {}",
+            self.renderer.quote(&self.table),
+            assignments
+        );
+        let mut values: Vec<Value> = self
+            .assignments
+            .iter()
+            .map(|(_, value)| value.clone())
+            .collect();
+
+        match self.renderer.filter_fragment()? {
+            Some(filter) => {
+                sql.push_str(" WHERE ");
+                sql.push_str(&filter.sql);
+                values.extend(filter.values);
+            }
+            None if self.unfiltered => {}
+            None => return Err(Error::MissingFilter(self.table.clone())),
+        }
+
+        Ok(Fragment {
+            sql: self.renderer.finish(&sql),
+            values,
+            compound: false,
+        })
+    }
+
+    /// The column an assignment writes, resolved through the schema if set
+    fn column(&self, name: &str) -> Result<String> {
+        let field = match self.renderer.schema {
+            Some(schema) => Some(schema.filter_field(name)?),
+            None => None,
+        };
+        Ok(self.renderer.column(name, field))
+    }
+}
@@ -0,0 +1,86 @@
+/// A value bound to a placeholder
+///
+/// Mirrors SQLite's storage classes. It binds directly to rusqlite statements
+/// through `ToSql` (`rusqlite` feature) and to sqlx queries through `Encode`
+/// for SQLite (`sqlx` feature).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for Value {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        use rusqlite::types::{ToSqlOutput, ValueRef};
+
+        Ok(ToSqlOutput::Borrowed(match self {
+            Value::Null => ValueRef::Null,
+            Value::Integer(integer) => ValueRef::Integer(*integer),
+            Value::Real(real) => ValueRef::Real(*real),
+            Value::Text(text) => ValueRef::Text(text.as_bytes()),
+            Value::Blob(bytes) => ValueRef::Blob(bytes),
+        }))
+    }
+}
+
+#[cfg(feature = "sqlx")]
+pub use sqlite::sqlx_arguments;
+
+#[cfg(feature = "sqlx")]
+mod sqlite {
+    use super::Value;
+    use crate::error::{Error, Result};
+    use sqlx_core::arguments::Arguments;
+    use sqlx_core::encode::{Encode, IsNull};
+    use sqlx_core::error::BoxDynError;
+    use sqlx_core::types::Type;
+    use sqlx_sqlite::{Sqlite, SqliteArguments, SqliteArgumentsBuffer, SqliteTypeInfo};
+
+    /// Values as sqlx arguments, e.g. for `sqlx::query_with(&sql, arguments)`
+    ///
+    /// A value that fails to encode is returned as `Error::Sqlx` rather than
+    /// left out, which would shift every later placeholder.
+    pub fn sqlx_arguments(values: Vec<Value>) -> Result<SqliteArguments> {
+        let mut arguments = SqliteArguments::default();
+        for value in values {
+            Arguments::add(&mut arguments, value)
+                .map_err(|error| Error::Sqlx(error.to_string()))?;
+        }
+        Ok(arguments)
+    }
+
+    impl Type<Sqlite> for Value {
+        fn type_info() -> SqliteTypeInfo {
+            <String as Type<Sqlite>>::type_info()
+        }
+    }
+
+    impl Encode<'_, Sqlite> for Value {
+        fn encode_by_ref(
+            &self,
+            buf: &mut SqliteArgumentsBuffer,
+        ) -> std::result::Result<IsNull, BoxDynError> {
+            match self {
+                Value::Null => Ok(IsNull::Yes),
+                Value::Integer(integer) => <i64 as Encode<Sqlite>>::encode_by_ref(integer, buf),
+                Value::Real(real) => <f64 as Encode<Sqlite>>::encode_by_ref(real, buf),
+                Value::Text(text) => <String as Encode<Sqlite>>::encode_by_ref(text, buf),
+                Value::Blob(bytes) => <Vec<u8> as Encode<Sqlite>>::encode_by_ref(bytes, buf),
+            }
+        }
+
+        fn produces(&self) -> Option<SqliteTypeInfo> {
+            match self {
+                Value::Null => None,
+                Value::Integer(_) => Some(<i64 as Type<Sqlite>>::type_info()),
+                Value::Real(_) => Some(<f64 as Type<Sqlite>>::type_info()),
+                Value::Text(_) => Some(<String as Type<Sqlite>>::type_info()),
+                Value::Blob(_) => Some(<Vec<u8> as Type<Sqlite>>::type_info()),
+            }
+        }
+    }
+}
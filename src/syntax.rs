@@ -0,0 +1,669 @@
+//! HTTP query string syntaxes
+//!
+//! `from_http` and `to_http` speak the crate's own `field=op:values` syntax,
+//! `Native`. A `Syntax` parses another convention into the same `Query` and
+//! renders it back, e.g. `JsonApi` for `filter[name][contains]=jo&sort=-created`.
+
+use crate::config::ParseConfig;
+use crate::error::{Error, Result};
+use crate::query::{
+    AMPERSAND, COLON, COMMA, Case, Cursor, EQUAL, Fields, Filter, Order, Parameter, Parameters,
+    QUESTION, Query, Similarity, SortDirection, url_decode, url_encode,
+};
+use indexmap::IndexMap;
+
+/// How a query is written in an HTTP query string
+pub trait Syntax {
+    /// Parse a query string, resolving pagination against the config's bounds
+    /// and policy
+    fn parse(&self, search: &str, config: &ParseConfig) -> Result<Query>;
+
+    /// Write the query string that `parse` reads back into the same query
+    fn render(&self, query: &Query) -> String;
+}
+
+/// `name=contains:jo&order=created:desc&limit=20&offset=40`, as `from_http`
+/// and `to_http` read and write it
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Native;
+
+impl Syntax for Native {
+    fn parse(&self, search: &str, config: &ParseConfig) -> Result<Query> {
+        Query::from_http_with_config(search.to_string(), config)
+    }
+
+    fn render(&self, query: &Query) -> String {
+        query.to_http()
+    }
+}
+
+/// JSON:API-style query strings
+///
+/// `filter[name][contains]=jo&filter[age][greater]=18&sort=-created,name&page[size]=20&page[number]=2`
+///
+/// - `filter[field][op]=values` takes the `Native` similarity names, including
+///   `-ci`; `filter[field]=values` means `equals`. Values are comma-separated.
+/// - `filter[and]`, `filter[or]` and `filter[not]` take a `Native` group, e.g.
+///   `filter[or]=(status=equals:draft,owner=equals:me)`
+/// - `sort` lists fields, descending with a `-` prefix
+/// - `page[size]` is the limit and `page[number]` the 1-based page; `page[offset]`
+///   and `page[cursor]` skip rows or seek past a cursor instead
+/// - `fields[type]=a,b` selects fields; other parameters, such as `include`,
+///   are left to the caller
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JsonApi {
+    resource: Option<String>,
+}
+
+impl JsonApi {
+    pub const FILTER: &str = "filter";
+    pub const SORT: &str = "sort";
+    pub const PAGE: &str = "page";
+    pub const SIZE: &str = "size";
+    pub const NUMBER: &str = "number";
+    pub const DESCENDING_PREFIX: char = '-';
+
+    pub fn new() -> Self {
+        Self { resource: None }
+    }
+
+    /// The resource type whose `fields[type]` selects fields; the fieldsets of
+    /// other types are ignored. Without one, every `fields[...]` is read and
+    /// `fields=a,b` is written.
+    pub fn resource(mut self, resource: String) -> Self {
+        self.resource = Some(resource);
+        self
+    }
+
+    fn selects(&self, resource: &str) -> bool {
+        self.resource.as_deref().is_none_or(|own| own == resource)
+    }
+}
+
+impl Syntax for JsonApi {
+    fn parse(&self, search: &str, config: &ParseConfig) -> Result<Query> {
+        let mut query = Query::new();
+        query.limit = config.initial_limit();
+        let mut page_number = None;
+
+        for pair in search.trim_start_matches(QUESTION).split(AMPERSAND) {
+            let pair = pair.trim();
+            if pair.is_empty() {
+                continue;
+            }
+
+            let (key, value) = pair.split_once(EQUAL).unwrap_or((pair, ""));
+            let key = url_decode(key.trim());
+            let value = value.trim();
+            let (family, path) =
+                split_key(&key).ok_or_else(|| Error::InvalidSearchParameters(pair.into()))?;
+
+            match (family, path.as_slice()) {
+                (JsonApi::FILTER, [group])
+                    if Filter::GROUPS.contains(group) && !value.is_empty() =>
+                {
+                    let filter = format!("{group}{EQUAL}{value}").parse::<Filter>()?;
+                    query.parameters.group(filter);
+                }
+                (JsonApi::FILTER, [group]) if Filter::GROUPS.contains(group) => {}
+                (JsonApi::FILTER, [field]) => {
+                    let condition = Parameter::init(Similarity::Equals, split_values(value));
+                    append(&mut query.parameters, field, condition);
+                }
+                (JsonApi::FILTER, [field, similarity]) => {
+                    let (parsed, case) = Parameter::parse_similarity(similarity)?;
+                    let condition = Parameter::init(parsed, split_values(value)).with_case(case);
+                    if !condition.is_valid() {
+                        return Err(Error::InvalidParameter(pair.into()));
+                    }
+                    append(&mut query.parameters, field, condition);
+                }
+                (JsonApi::FILTER, _) => return Err(Error::InvalidFilter(pair.into())),
+                // Like `from_http`, empty values are skipped
+                (_, _) if value.is_empty() => {}
+                (JsonApi::SORT, []) => query.order = parse_sort(value)?,
+                (JsonApi::PAGE, [JsonApi::SIZE]) => query.limit = config.parse_limit(value)?,
+                (JsonApi::PAGE, [JsonApi::NUMBER]) => page_number = Some(value),
+                (JsonApi::PAGE, [Parameters::OFFSET]) => {
+                    query.offset = config.parse_offset(value)?
+                }
+                (JsonApi::PAGE, [Parameters::CURSOR]) => {
+                    query.cursor = Some(value.parse::<Cursor>()?)
+                }
+                (JsonApi::PAGE, _) => return Err(Error::InvalidSearchParameters(pair.into())),
+                (Parameters::FIELDS, []) => query.fields = value.parse::<Fields>()?,
+                (Parameters::FIELDS, [resource]) if self.selects(resource) => {
+                    query.fields = value.parse::<Fields>()?
+                }
+                _ => {}
+            }
+        }
+
+        // The offset of a page depends on its size, which may come later
+        if let Some(number) = page_number {
+            query.offset = config.parse_page(number, query.limit)?;
+        }
+
+        if let Some(cursor) = &query.cursor {
+            cursor.check(&query.order)?;
+        }
+
+        Ok(query)
+    }
+
+    fn render(&self, query: &Query) -> String {
+        let mut pairs = Vec::new();
+
+        for (key, conditions) in query.parameters.inner() {
+            pairs.extend(condition_pairs(key, conditions));
+        }
+        for filter in query.parameters.groups() {
+            match filter {
+                Filter::Condition(key, parameter) => {
+                    pairs.extend(condition_pairs(key, std::slice::from_ref(parameter)))
+                }
+                group => {
+                    // A group keeps its native form, e.g. `or=(...)`
+                    let text = group.to_string();
+                    if let Some((name, rest)) = text.split_once(EQUAL) {
+                        pairs.push(format!("{}[{name}]{EQUAL}{rest}", JsonApi::FILTER));
+                    }
+                }
+            }
+        }
+
+        if !query.order.inner().is_empty() {
+            pairs.push(format!(
+                "{}{EQUAL}{}",
+                JsonApi::SORT,
+                render_sort(&query.order)
+            ));
+        }
+
+        if !query.fields.inner().is_empty() {
+            match &self.resource {
+                Some(resource) => pairs.push(format!(
+                    "{}[{resource}]{EQUAL}{}",
+                    Parameters::FIELDS,
+                    query.fields
+                )),
+                None => pairs.push(format!("{}{EQUAL}{}", Parameters::FIELDS, query.fields)),
+            }
+        }
+
+        let page = |name: &str, value: String| format!("{}[{name}]{EQUAL}{value}", JsonApi::PAGE);
+        pairs.push(page(JsonApi::SIZE, query.limit.to_string()));
+        match &query.cursor {
+            Some(cursor) => pairs.push(page(Parameters::CURSOR, cursor.to_string())),
+            None if query.limit > 0 && query.offset.is_multiple_of(query.limit) => {
+                pairs.push(page(
+                    JsonApi::NUMBER,
+                    (query.offset / query.limit + 1).to_string(),
+                ))
+            }
+            None => pairs.push(page(Parameters::OFFSET, query.offset.to_string())),
+        }
+
+        pairs.join(&AMPERSAND.to_string())
+    }
+}
+
+/// Operator names that a key-based syntax maps onto similarities, e.g. `gte`
+/// for `greater-or-equal`
+///
+/// Several aliases may share a similarity; rendering writes the first one.
+/// Operators missing from the table are still read by their native names,
+/// e.g. `age__not-between=1,5`.
+///
+/// # Examples
+/// - `Aliases::django().alias("ne".into(), Similarity::NotEquals, Case::Sensitive)`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Aliases(IndexMap<String, (Similarity, Case)>);
+
+impl Aliases {
+    pub fn new() -> Self {
+        Self(IndexMap::new())
+    }
+
+    /// Django's field lookups: `exact`, `iexact`, `contains`, `icontains`,
+    /// `startswith`, `istartswith`, `endswith`, `iendswith`, `gt`, `gte`,
+    /// `lt`, `lte`, `in`, `range` and `isnull`
+    pub fn django() -> Self {
+        use Case::{Insensitive, Sensitive};
+        use Similarity::*;
+        Self::from_table(&[
+            ("in", Equals, Sensitive),
+            ("exact", Equals, Sensitive),
+            ("iexact", Equals, Insensitive),
+            ("contains", Contains, Sensitive),
+            ("icontains", Contains, Insensitive),
+            ("startswith", StartsWith, Sensitive),
+            ("istartswith", StartsWith, Insensitive),
+            ("endswith", EndsWith, Sensitive),
+            ("iendswith", EndsWith, Insensitive),
+            ("gt", Greater, Sensitive),
+            ("gte", GreaterOrEqual, Sensitive),
+            ("lt", Lesser, Sensitive),
+            ("lte", LesserOrEqual, Sensitive),
+            ("range", Between, Sensitive),
+            ("isnull", IsNull, Sensitive),
+        ])
+    }
+
+    /// Ransack-style predicates common in Rails APIs: `eq`, `in`, `not_eq`,
+    /// `not_in`, `lt`, `lte`, `gt`, `gte`, `cont`, `i_cont`, `not_cont`,
+    /// `start`, `not_start`, `end`, `not_end`, `between`, `not_between`,
+    /// `like`, `null` and `not_null`
+    pub fn rails() -> Self {
+        use Case::{Insensitive, Sensitive};
+        use Similarity::*;
+        Self::from_table(&[
+            ("in", Equals, Sensitive),
+            ("eq", Equals, Sensitive),
+            ("i_eq", Equals, Insensitive),
+            ("not_eq", NotEquals, Sensitive),
+            ("not_in", NotEquals, Sensitive),
+            ("lt", Lesser, Sensitive),
+            ("lte", LesserOrEqual, Sensitive),
+            ("gt", Greater, Sensitive),
+            ("gte", GreaterOrEqual, Sensitive),
+            ("cont", Contains, Sensitive),
+            ("i_cont", Contains, Insensitive),
+            ("not_cont", NotContains, Sensitive),
+            ("start", StartsWith, Sensitive),
+            ("not_start", NotStartsWith, Sensitive),
+            ("end", EndsWith, Sensitive),
+            ("not_end", NotEndsWith, Sensitive),
+            ("between", Between, Sensitive),
+            ("not_between", NotBetween, Sensitive),
+            ("like", Like, Sensitive),
+            ("null", IsNull, Sensitive),
+            ("not_null", IsNotNull, Sensitive),
+        ])
+    }
+
+    fn from_table(table: &[(&str, Similarity, Case)]) -> Self {
+        Self(
+            table
+                .iter()
+                .map(|(alias, similarity, case)| (alias.to_string(), (similarity.clone(), *case)))
+                .collect(),
+        )
+    }
+
+    /// Add an alias, or point an existing one at another similarity
+    pub fn alias(mut self, alias: String, similarity: Similarity, case: Case) -> Self {
+        self.0.insert(alias, (similarity, case));
+        self
+    }
+
+    pub fn remove(mut self, alias: &str) -> Self {
+        self.0.shift_remove(alias);
+        self
+    }
+
+    pub fn inner(&self) -> &IndexMap<String, (Similarity, Case)> {
+        &self.0
+    }
+
+    /// The similarity and case of an alias
+    pub fn get(&self, alias: &str) -> Option<&(Similarity, Case)> {
+        self.0.get(alias)
+    }
+
+    /// The first alias of a similarity and case
+    pub fn find(&self, similarity: &Similarity, case: Case) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(_, entry)| entry.0 == *similarity && entry.1 == case)
+            .map(|(alias, _)| alias.as_str())
+    }
+}
+
+/// Django-style `field__op=value` keys, e.g. `age__gte=18&name__icontains=jo`
+///
+/// - A key without a known operator is a field compared with `equals`, so
+///   `author__name=jo` filters on `author__name`
+/// - Values after an operator are comma-separated; a plain `field=value` is a
+///   single literal value
+/// - `isnull=true` / `isnull=false` select `is-null` or `is-not-null`
+/// - `order`, `fields`, `limit`, `offset`, `cursor` and `and`/`or`/`not`
+///   groups keep the native syntax
+#[derive(Clone, Debug, PartialEq)]
+pub struct Django {
+    aliases: Aliases,
+}
+
+impl Django {
+    pub const SEPARATOR: &str = "__";
+
+    pub fn new() -> Self {
+        Self {
+            aliases: Aliases::django(),
+        }
+    }
+
+    /// Replace the operator alias table
+    pub fn aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+}
+
+impl Default for Django {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Syntax for Django {
+    fn parse(&self, search: &str, config: &ParseConfig) -> Result<Query> {
+        parse_keyed(search, config, &self.aliases, KeyStyle::Suffix)
+    }
+
+    fn render(&self, query: &Query) -> String {
+        render_keyed(query, &self.aliases, KeyStyle::Suffix)
+    }
+}
+
+/// Rails-style `field[op]=value` keys, e.g. `price[lt]=100&name[i_cont]=jo`
+///
+/// Values, null checks and the native parameters work as for `Django`. An
+/// operator that is neither an alias nor a native similarity name fails with
+/// `Error::InvalidSimilarity`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rails {
+    aliases: Aliases,
+}
+
+impl Rails {
+    pub fn new() -> Self {
+        Self {
+            aliases: Aliases::rails(),
+        }
+    }
+
+    /// Replace the operator alias table
+    pub fn aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+}
+
+impl Default for Rails {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Syntax for Rails {
+    fn parse(&self, search: &str, config: &ParseConfig) -> Result<Query> {
+        parse_keyed(search, config, &self.aliases, KeyStyle::Bracket)
+    }
+
+    fn render(&self, query: &Query) -> String {
+        render_keyed(query, &self.aliases, KeyStyle::Bracket)
+    }
+}
+
+/// Where a key-based syntax puts the operator
+#[derive(Clone, Copy, PartialEq)]
+enum KeyStyle {
+    /// `field__op`
+    Suffix,
+    /// `field[op]`
+    Bracket,
+}
+
+impl KeyStyle {
+    fn split(self, key: &str) -> Option<(&str, &str)> {
+        let (field, operator) = match self {
+            KeyStyle::Suffix => key.rsplit_once(Django::SEPARATOR)?,
+            KeyStyle::Bracket => key.strip_suffix(']')?.split_once('[')?,
+        };
+        (!field.is_empty() && !operator.is_empty()).then_some((field, operator))
+    }
+
+    fn join(self, field: &str, operator: &str) -> String {
+        match self {
+            KeyStyle::Suffix => format!("{field}{}{operator}", Django::SEPARATOR),
+            KeyStyle::Bracket => format!("{field}[{operator}]"),
+        }
+    }
+}
+
+/// Split `filter[name][contains]` into `filter` and `["name", "contains"]`
+fn split_key(key: &str) -> Option<(&str, Vec<&str>)> {
+    let (family, mut rest) = match key.find('[') {
+        Some(start) => (&key[..start], &key[start..]),
+        None => (key, ""),
+    };
+
+    let mut path = Vec::new();
+    while !rest.is_empty() {
+        let (segment, after) = rest.strip_prefix('[')?.split_once(']')?;
+        path.push(segment.trim());
+        rest = after;
+    }
+    Some((family, path))
+}
+
+fn split_values(value: &str) -> Vec<String> {
+    value
+        .split(COMMA)
+        .map(|v| url_decode(v.trim()))
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+fn append(parameters: &mut Parameters, field: &str, condition: Parameter) {
+    // Only add parameters that have values
+    if !condition.is_empty() {
+        parameters.append(field.to_string(), condition);
+    }
+}
+
+fn parse_sort(value: &str) -> Result<Order> {
+    let mut order = Order::new();
+    for field in value.split(COMMA).map(str::trim).filter(|f| !f.is_empty()) {
+        let (name, direction) = match field.strip_prefix(JsonApi::DESCENDING_PREFIX) {
+            Some(name) => (name, SortDirection::Descending),
+            None => (field, SortDirection::Ascending),
+        };
+        let name = url_decode(name.trim());
+        if name.is_empty() {
+            return Err(Error::InvalidOrderField(field.into()));
+        }
+        order.inner_mut().insert(name, direction);
+    }
+    Ok(order)
+}
+
+fn render_sort(order: &Order) -> String {
+    order
+        .inner()
+        .iter()
+        .map(|(name, direction)| match direction {
+            SortDirection::Ascending => url_encode(name),
+            SortDirection::Descending => {
+                format!("{}{}", JsonApi::DESCENDING_PREFIX, url_encode(name))
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(&COMMA.to_string())
+}
+
+/// `filter[field][op]=values` for each condition; case-sensitive `equals` is
+/// written as `filter[field]=values`
+fn condition_pairs(key: &str, conditions: &[Parameter]) -> Vec<String> {
+    conditions
+        .iter()
+        .filter(|condition| !condition.is_empty())
+        .map(|condition| {
+            let text = condition.to_string();
+            let (similarity, values) = text.split_once(COLON).unwrap_or((&text, ""));
+            if *condition.similarity() == Similarity::Equals && *condition.case() == Case::Sensitive
+            {
+                format!("{}[{key}]{EQUAL}{values}", JsonApi::FILTER)
+            } else {
+                format!("{}[{key}][{similarity}]{EQUAL}{values}", JsonApi::FILTER)
+            }
+        })
+        .collect()
+}
+
+/// Read the filters of a key-based syntax into conditions; the reserved keys
+/// go to `from_http_with_config` untouched
+fn parse_keyed(
+    search: &str,
+    config: &ParseConfig,
+    aliases: &Aliases,
+    style: KeyStyle,
+) -> Result<Query> {
+    let mut parameters = Parameters::new();
+    let mut reserved = Vec::new();
+
+    for pair in search.trim_start_matches(QUESTION).split(AMPERSAND) {
+        let pair = pair.trim();
+        if pair.is_empty() {
+            continue;
+        }
+
+        let (key, value) = pair.split_once(EQUAL).unwrap_or((pair, ""));
+        let key = url_decode(key.trim());
+        let value = value.trim();
+
+        // Pagination, order, fields and groups keep the native syntax
+        if Parameters::EXCLUDE.contains(&key.as_str()) || Filter::GROUPS.contains(&key.as_str()) {
+            reserved.push(pair);
+            continue;
+        }
+
+        let condition = match style.split(&key) {
+            Some((field, operator)) => match operator_similarity(aliases, operator) {
+                Some((similarity, case)) => Some((field, similarity, case)),
+                None if style == KeyStyle::Suffix => None,
+                None => return Err(Error::InvalidSimilarity(operator.into())),
+            },
+            None => None,
+        };
+
+        match condition {
+            Some((field, similarity, case)) if similarity.takes_values() => {
+                let condition = Parameter::init(similarity, split_values(value)).with_case(case);
+                append(&mut parameters, field, condition);
+            }
+            Some((field, similarity, case)) => {
+                // `isnull=false` asks for the opposite check
+                let similarity = match url_decode(value).to_lowercase().as_str() {
+                    "" | "true" | "1" => similarity,
+                    "false" | "0" => opposite_null_check(&similarity),
+                    _ => return Err(Error::InvalidParameter(pair.into())),
+                };
+                let condition = Parameter::init(similarity, vec![]).with_case(case);
+                if !condition.is_valid() {
+                    return Err(Error::InvalidParameter(pair.into()));
+                }
+                append(&mut parameters, field, condition);
+            }
+            // A plain value is one literal value, even with commas or colons
+            None if !value.is_empty() => {
+                parameters.append_value(key, url_decode(value));
+            }
+            None => {}
+        }
+    }
+
+    let mut query = Query::from_http_with_config(reserved.join(&AMPERSAND.to_string()), config)?;
+    parameters
+        .groups_mut()
+        .append(query.parameters.groups_mut());
+    query.parameters = parameters;
+    Ok(query)
+}
+
+/// An alias, or else a native similarity name such as `not-between`
+fn operator_similarity(aliases: &Aliases, operator: &str) -> Option<(Similarity, Case)> {
+    aliases
+        .get(operator)
+        .cloned()
+        .or_else(|| Parameter::parse_similarity(operator).ok())
+}
+
+fn opposite_null_check(similarity: &Similarity) -> Similarity {
+    match similarity {
+        Similarity::IsNull => Similarity::IsNotNull,
+        _ => Similarity::IsNull,
+    }
+}
+
+fn render_keyed(query: &Query, aliases: &Aliases, style: KeyStyle) -> String {
+    let mut pairs = Vec::new();
+
+    for (key, conditions) in query.parameters.inner() {
+        pairs.extend(keyed_pairs(key, conditions, aliases, style));
+    }
+    for filter in query.parameters.groups() {
+        if let Filter::Condition(key, parameter) = filter {
+            let conditions = std::slice::from_ref(parameter);
+            pairs.extend(keyed_pairs(key, conditions, aliases, style));
+        }
+    }
+
+    // Groups, order, fields and pagination as `to_http` writes them
+    let mut rest = query.clone();
+    rest.parameters.inner_mut().clear();
+    rest.parameters
+        .groups_mut()
+        .retain(|filter| !matches!(filter, Filter::Condition(..)));
+    pairs.push(rest.to_http());
+
+    pairs.join(&AMPERSAND.to_string())
+}
+
+/// `field__op=values` or `field[op]=values` for each condition; a single
+/// case-sensitive `equals` value is written as `field=value`
+fn keyed_pairs(
+    key: &str,
+    conditions: &[Parameter],
+    aliases: &Aliases,
+    style: KeyStyle,
+) -> Vec<String> {
+    conditions
+        .iter()
+        .filter(|condition| !condition.is_empty())
+        .map(|condition| {
+            let similarity = condition.similarity();
+            let case = *condition.case();
+            let values = condition.values();
+
+            if *similarity == Similarity::Equals && case == Case::Sensitive && values.len() == 1 {
+                return format!("{key}{EQUAL}{}", url_encode(&values[0]));
+            }
+
+            if !similarity.takes_values() {
+                let opposite = opposite_null_check(similarity);
+                return match (
+                    aliases.find(similarity, case),
+                    aliases.find(&opposite, case),
+                ) {
+                    (Some(alias), _) => format!("{}{EQUAL}true", style.join(key, alias)),
+                    (None, Some(alias)) => format!("{}{EQUAL}false", style.join(key, alias)),
+                    (None, None) => format!("{}{EQUAL}", style.join(key, &similarity.to_string())),
+                };
+            }
+
+            let operator = match (aliases.find(similarity, case), case) {
+                (Some(alias), _) => alias.to_string(),
+                (None, Case::Sensitive) => similarity.to_string(),
+                (None, Case::Insensitive) => format!("{similarity}{}", Case::INSENSITIVE_SUFFIX),
+            };
+            let values = values
+                .iter()
+                .map(|value| url_encode(value))
+                .collect::<Vec<String>>()
+                .join(&COMMA.to_string());
+            format!("{}{EQUAL}{values}", style.join(key, &operator))
+        })
+        .collect()
+}
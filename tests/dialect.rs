@@ -0,0 +1,372 @@
+#![cfg(feature = "sql")]
+
+use query_lite::sql::Dialect;
+use query_lite::*;
+
+// ============================================================================
+// DIALECT TESTS
+// ============================================================================
+
+fn query(parameters: &str) -> Query {
+    Query::from(parameters.parse::<Parameters>().unwrap())
+}
+
+fn users() -> Query {
+    let mut query = query("name=equals:john,jane&age=greater:18");
+    query.order.descending("age".to_string());
+    query.limit = 10;
+    query
+}
+
+#[test]
+fn test_dialect_placeholders() {
+    assert_eq!(sql::Sqlite.placeholder(3), "?");
+    assert_eq!(sql::Postgres.placeholder(3), "$3");
+    assert_eq!(sql::MySql.placeholder(3), "?");
+}
+
+#[test]
+fn test_dialect_quote_identifier() {
+    assert_eq!(sql::Sqlite.quote_identifier("u.name"), "\"u\".\"name\"");
+    assert_eq!(
+        sql::Postgres.quote_identifier("say \"hi\""),
+        "\"say \"\"hi\"\"\""
+    );
+    assert_eq!(sql::MySql.quote_identifier("u.*"), "`u`.*");
+    assert_eq!(sql::MySql.quote_identifier("odd`name"), "`odd``name`");
+    assert_eq!(sql::quote_identifier("u.name"), "\"u\".\"name\"");
+}
+
+#[test]
+fn test_dialect_default_is_sqlite() {
+    let query = users();
+    let sqlite = query.renderer().dialect(&sql::Sqlite);
+    assert_eq!(query.renderer().to_sql(), sqlite.to_sql());
+    assert_eq!(
+        sqlite.to_sql().unwrap(),
+        "WHERE \"name\" IN (?, ?) AND \"age\" > ? ORDER BY \"age\" DESC LIMIT ? OFFSET ?"
+    );
+}
+
+#[test]
+fn test_dialect_postgres_numbers_placeholders() {
+    let query = users();
+    let renderer = query.renderer().dialect(&sql::Postgres);
+    assert_eq!(
+        renderer.to_sql().unwrap(),
+        "WHERE \"name\" IN ($1, $2) AND \"age\" > $3 ORDER BY \"age\" DESC LIMIT $4 OFFSET $5"
+    );
+    assert_eq!(
+        renderer.where_clause().unwrap().unwrap(),
+        "\"name\" IN ($1, $2) AND \"age\" > $3"
+    );
+    assert_eq!(
+        renderer.to_count_sql("users").unwrap(),
+        "SELECT COUNT(*) FROM \"users\" WHERE \"name\" IN ($1, $2) AND \"age\" > $3"
+    );
+    assert_eq!(renderer.to_values().unwrap().len(), 5);
+}
+
+#[test]
+fn test_dialect_mysql_placeholders() {
+    let query = users();
+    let renderer = query.renderer().dialect(&sql::MySql);
+    assert_eq!(
+        renderer.to_sql().unwrap(),
+        "WHERE `name` IN (?, ?) AND `age` > ? ORDER BY `age` DESC LIMIT ? OFFSET ?"
+    );
+}
+
+#[test]
+fn test_dialect_like_escape() {
+    let query = query("name=contains:jo&email=not-ends-with:.org");
+    assert_eq!(
+        query.renderer().where_clause().unwrap().unwrap(),
+        "\"name\" LIKE ? ESCAPE '\\' AND \"email\" NOT LIKE ? ESCAPE '\\'"
+    );
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::Postgres)
+            .where_clause()
+            .unwrap()
+            .unwrap(),
+        "\"name\" LIKE $1 ESCAPE '\\' AND \"email\" NOT LIKE $2 ESCAPE '\\'"
+    );
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::MySql)
+            .where_clause()
+            .unwrap()
+            .unwrap(),
+        "`name` LIKE ? ESCAPE '\\\\' AND `email` NOT LIKE ? ESCAPE '\\\\'"
+    );
+}
+
+#[test]
+fn test_dialect_case_insensitive_patterns() {
+    let query = query("name=contains-ci:JO&email=not-starts-with-ci:ADMIN");
+    assert_eq!(
+        query.renderer().where_clause().unwrap().unwrap(),
+        "lower(\"name\") LIKE lower(?) ESCAPE '\\' AND lower(\"email\") NOT LIKE lower(?) ESCAPE '\\'"
+    );
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::Postgres)
+            .where_clause()
+            .unwrap()
+            .unwrap(),
+        "\"name\" ILIKE $1 ESCAPE '\\' AND \"email\" NOT ILIKE $2 ESCAPE '\\'"
+    );
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::MySql)
+            .where_clause()
+            .unwrap()
+            .unwrap(),
+        "lower(`name`) LIKE lower(?) ESCAPE '\\\\' AND lower(`email`) NOT LIKE lower(?) ESCAPE '\\\\'"
+    );
+}
+
+#[test]
+fn test_dialect_case_insensitive_equals_folds() {
+    let query = query("name=equals-ci:JOHN");
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::Postgres)
+            .where_clause()
+            .unwrap()
+            .unwrap(),
+        "lower(\"name\") = lower($1)"
+    );
+}
+
+#[test]
+fn test_dialect_null_sentinel() {
+    let query = query("deleted=equals:null&owner=not-equals:null,bob");
+    assert_eq!(
+        query.renderer().where_clause().unwrap().unwrap(),
+        "\"deleted\" IS ? AND (\"owner\" IS NOT ? AND \"owner\" <> ?)"
+    );
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::Postgres)
+            .where_clause()
+            .unwrap()
+            .unwrap(),
+        "\"deleted\" IS NOT DISTINCT FROM $1 AND (\"owner\" IS DISTINCT FROM $2 AND \"owner\" <> $3)"
+    );
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::MySql)
+            .where_clause()
+            .unwrap()
+            .unwrap(),
+        "`deleted` <=> ? AND (NOT (`owner` <=> ?) AND `owner` <> ?)"
+    );
+}
+
+#[test]
+fn test_dialect_cursor_numbers_after_filters() {
+    let mut query = query("status=equals:active");
+    query.order.descending("score".to_string());
+    query.order.ascending("id".to_string());
+    query.limit = 5;
+    query.cursor = Some(Cursor::new(vec!["90".to_string(), "7".to_string()]));
+    assert_eq!(
+        query.renderer().dialect(&sql::Postgres).to_sql().unwrap(),
+        "WHERE \"status\" = $1 AND (\"score\" < $2 OR (\"score\" = $3 AND \"id\" > $4)) \
+         ORDER BY \"score\" DESC, \"id\" ASC LIMIT $5"
+    );
+}
+
+#[test]
+fn test_dialect_select() {
+    let mut query = query("age=greater:18");
+    query.fields = "id,name".parse().unwrap();
+    let select = |dialect: &'static dyn sql::Dialect| {
+        query
+            .renderer()
+            .dialect(dialect)
+            .select("users")
+            .alias("u")
+            .join(sql::Join::left("posts", "p.user_id = u.id").alias("p"))
+            .to_sql()
+            .unwrap()
+    };
+    assert_eq!(
+        select(&sql::Sqlite),
+        "SELECT \"id\", \"name\" FROM \"users\" AS \"u\" \
+         LEFT JOIN \"posts\" AS \"p\" ON p.user_id = u.id \
+         WHERE \"age\" > ? LIMIT ? OFFSET ?"
+    );
+    assert_eq!(
+        select(&sql::Postgres),
+        "SELECT \"id\", \"name\" FROM \"users\" AS \"u\" \
+         LEFT JOIN \"posts\" AS \"p\" ON p.user_id = u.id \
+         WHERE \"age\" > $1 LIMIT $2 OFFSET $3"
+    );
+    assert_eq!(
+        select(&sql::MySql),
+        "SELECT `id`, `name` FROM `users` AS `u` \
+         LEFT JOIN `posts` AS `p` ON p.user_id = u.id \
+         WHERE `age` > ? LIMIT ? OFFSET ?"
+    );
+}
+
+#[test]
+fn test_dialect_select_count() {
+    let query = query("age=greater:18");
+    let select = query
+        .renderer()
+        .dialect(&sql::MySql)
+        .select("users")
+        .columns(&["u.id"]);
+    assert_eq!(
+        select.to_sql().unwrap(),
+        "SELECT `u`.`id` FROM `users` WHERE `age` > ? LIMIT ? OFFSET ?"
+    );
+    assert_eq!(
+        select.to_count_sql().unwrap(),
+        "SELECT COUNT(*) FROM `users` WHERE `age` > ?"
+    );
+}
+
+#[test]
+fn test_dialect_update_numbers_assignments_first() {
+    let query = query("status=equals:draft&created=lesser:100");
+    let update = query
+        .renderer()
+        .dialect(&sql::Postgres)
+        .update("posts")
+        .set("status", sql::Value::Text("archived".to_string()))
+        .set("note", sql::Value::Null);
+    assert_eq!(
+        update.to_sql().unwrap(),
+        "UPDATE \"posts\" SET \"status\" = $1, \"note\" = $2 WHERE \"status\" = $3 AND \"created\" < $4"
+    );
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::MySql)
+            .update("posts")
+            .set("status", sql::Value::Text("archived".to_string()))
+            .to_sql()
+            .unwrap(),
+        "UPDATE `posts` SET `status` = ? WHERE `status` = ? AND `created` < ?"
+    );
+}
+
+#[test]
+fn test_dialect_delete() {
+    let query = query("status=equals:draft");
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::Postgres)
+            .delete("posts")
+            .to_sql()
+            .unwrap(),
+        "DELETE FROM \"posts\" WHERE \"status\" = $1"
+    );
+    assert_eq!(
+        query
+            .renderer()
+            .dialect(&sql::MySql)
+            .delete("posts")
+            .to_sql()
+            .unwrap(),
+        "DELETE FROM `posts` WHERE `status` = ?"
+    );
+}
+
+#[test]
+fn test_dialect_join_display_is_sqlite() {
+    let join = sql::Join::inner("posts", "p.user_id = u.id").alias("p");
+    assert_eq!(
+        join.to_string(),
+        "INNER JOIN \"posts\" AS \"p\" ON p.user_id = u.id"
+    );
+    assert_eq!(
+        join.render(&sql::MySql),
+        "INNER JOIN `posts` AS `p` ON p.user_id = u.id"
+    );
+}
+
+#[test]
+fn test_placeholders_numbered_from_offset() {
+    let query = users();
+    let renderer = query
+        .renderer()
+        .placeholders(sql::Placeholders::Numbered)
+        .placeholder_offset(2);
+    assert_eq!(
+        renderer.to_sql().unwrap(),
+        "WHERE \"name\" IN (?3, ?4) AND \"age\" > ?5 ORDER BY \"age\" DESC LIMIT ?6 OFFSET ?7"
+    );
+    assert_eq!(
+        renderer.where_clause().unwrap().unwrap(),
+        "\"name\" IN (?3, ?4) AND \"age\" > ?5"
+    );
+}
+
+#[test]
+fn test_placeholders_named() {
+    let query = users();
+    let renderer = query.renderer().placeholders(sql::Placeholders::Named);
+    assert_eq!(
+        renderer.where_clause().unwrap().unwrap(),
+        "\"name\" IN (:p1, :p2) AND \"age\" > :p3"
+    );
+
+    let values = renderer.named_values(renderer.parameter_values().unwrap());
+    assert_eq!(
+        values.into_iter().collect::<Vec<_>>(),
+        vec![
+            (":p1".to_string(), sql::Value::Text("john".to_string())),
+            (":p2".to_string(), sql::Value::Text("jane".to_string())),
+            (":p3".to_string(), sql::Value::Integer(18)),
+        ]
+    );
+}
+
+#[test]
+fn test_placeholders_offset_applies_to_dialect() {
+    let query = users();
+    let renderer = query
+        .renderer()
+        .dialect(&sql::Postgres)
+        .placeholder_offset(1);
+    assert_eq!(
+        renderer.where_clause().unwrap().unwrap(),
+        "\"name\" IN ($2, $3) AND \"age\" > $4"
+    );
+    assert_eq!(
+        renderer
+            .named_values(renderer.parameter_values().unwrap())
+            .keys()
+            .collect::<Vec<_>>(),
+        vec!["$2", "$3", "$4"]
+    );
+}
+
+#[test]
+fn test_placeholders_update_named() {
+    let query = query("status=equals:draft");
+    let update = query
+        .renderer()
+        .placeholders(sql::Placeholders::Named)
+        .placeholder_offset(10)
+        .update("posts")
+        .set("status", sql::Value::Text("archived".to_string()));
+    assert_eq!(
+        update.to_sql().unwrap(),
+        "UPDATE \"posts\" SET \"status\" = :p11 WHERE \"status\" = :p12"
+    );
+}
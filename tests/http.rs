@@ -1,3 +1,5 @@
+#![cfg(feature = "http")]
+
 use query_lite::error::Error;
 use query_lite::*;
 
@@ -19,7 +21,10 @@ fn test_query_to_http_with_params() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["damian".to_string()],
+        )],
     );
 
     let http = query.to_http();
@@ -46,7 +51,10 @@ fn test_query_to_http_with_params_and_sort() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["damian".to_string()],
+        )],
     );
 
     query.order.descending("date_created".to_string());
@@ -64,7 +72,7 @@ fn test_query_to_http_order_empty_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec![]),
+        vec![Parameter::init(Similarity::Contains, vec![])],
     );
 
     let http = query.to_http();
@@ -219,17 +227,17 @@ fn test_query_from_http_with_numeric_comparisons() {
     assert_eq!(query.parameters.inner().len(), 3);
 
     assert!(query.parameters.inner().contains_key("age"));
-    let param = &query.parameters.inner()["age"];
+    let param = &query.parameters.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), vec!["20", "30"]);
 
     assert!(query.parameters.inner().contains_key("price"));
-    let param = &query.parameters.inner()["price"];
+    let param = &query.parameters.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::Greater);
     assert_eq!(*param.values(), vec!["100"]);
 
     assert!(query.parameters.inner().contains_key("score"));
-    let param = &query.parameters.inner()["score"];
+    let param = &query.parameters.inner()["score"][0];
     assert_eq!(*param.similarity(), Similarity::LesserOrEqual);
     assert_eq!(*param.values(), vec!["85"]);
 
@@ -251,22 +259,22 @@ fn test_query_from_http_mixed_similarity_types() {
     assert_eq!(query.parameters.inner().len(), 4);
 
     assert!(query.parameters.inner().contains_key("name"));
-    let param = &query.parameters.inner()["name"];
+    let param = &query.parameters.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), vec!["damian"]);
 
     assert!(query.parameters.inner().contains_key("age"));
-    let param = &query.parameters.inner()["age"];
+    let param = &query.parameters.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), vec!["25", "35"]);
 
     assert!(query.parameters.inner().contains_key("price"));
-    let param = &query.parameters.inner()["price"];
+    let param = &query.parameters.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::GreaterOrEqual);
     assert_eq!(*param.values(), vec!["50"]);
 
     assert!(query.parameters.inner().contains_key("status"));
-    let param = &query.parameters.inner()["status"];
+    let param = &query.parameters.inner()["status"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["active"]);
 
@@ -286,18 +294,24 @@ fn test_query_to_http_with_numeric_comparisons() {
 
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec!["20".to_string(), "30".to_string()],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Greater, vec!["100".to_string()]),
+        vec![Parameter::init(
+            Similarity::Greater,
+            vec!["100".to_string()],
+        )],
     );
     query.parameters.inner_mut().insert(
         "score".to_string(),
-        Parameter::init(Similarity::LesserOrEqual, vec!["85".to_string()]),
+        vec![Parameter::init(
+            Similarity::LesserOrEqual,
+            vec!["85".to_string()],
+        )],
     );
 
     query.order.descending("date_created".to_string());
@@ -364,7 +378,7 @@ fn test_edge_case_whitespace_handling() {
     assert_eq!(query.parameters.inner().len(), 1);
     assert!(query.parameters.inner().contains_key("name"));
 
-    let param = &query.parameters.inner()["name"];
+    let param = &query.parameters.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), vec!["damian"]);
 
@@ -396,10 +410,10 @@ fn test_edge_case_special_characters_in_values() {
 
     assert_eq!(query.parameters.inner().len(), 2);
 
-    let param = &query.parameters.inner()["name"];
+    let param = &query.parameters.inner()["name"][0];
     assert_eq!(*param.values(), vec!["damian test"]);
 
-    let param = &query.parameters.inner()["surname"];
+    let param = &query.parameters.inner()["surname"][0];
     assert_eq!(*param.values(), vec!["black", "steel", "wood"]);
 }
 
@@ -427,7 +441,7 @@ fn test_edge_case_unicode_characters() {
 
     assert_eq!(query.parameters.inner().len(), 2);
 
-    let param = &query.parameters.inner()["name"];
+    let param = &query.parameters.inner()["name"][0];
     assert_eq!(*param.values(), vec!["damian_测试"]);
 }
 
@@ -483,15 +497,15 @@ fn test_edge_case_numeric_comparisons_with_whitespace() {
 
     assert_eq!(query.parameters.inner().len(), 3);
 
-    let param = &query.parameters.inner()["age"];
+    let param = &query.parameters.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), vec!["20", "30"]);
 
-    let param = &query.parameters.inner()["price"];
+    let param = &query.parameters.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::Greater);
     assert_eq!(*param.values(), vec!["100"]);
 
-    let param = &query.parameters.inner()["score"];
+    let param = &query.parameters.inner()["score"][0];
     assert_eq!(*param.similarity(), Similarity::LesserOrEqual);
     assert_eq!(*param.values(), vec!["85"]);
 }
@@ -528,15 +542,15 @@ fn test_edge_case_numeric_comparisons_special_characters() {
 
     assert_eq!(query.parameters.inner().len(), 3);
 
-    let param = &query.parameters.inner()["price"];
+    let param = &query.parameters.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), vec!["100.50", "200.75"]);
 
-    let param = &query.parameters.inner()["discount"];
+    let param = &query.parameters.inner()["discount"][0];
     assert_eq!(*param.similarity(), Similarity::Greater);
     assert_eq!(*param.values(), vec!["10%"]);
 
-    let param = &query.parameters.inner()["score"];
+    let param = &query.parameters.inner()["score"][0];
     assert_eq!(*param.similarity(), Similarity::LesserOrEqual);
     assert_eq!(*param.values(), vec!["85.5"]);
 }
@@ -547,11 +561,11 @@ fn test_parameter_trait_with_query_parsing() {
     let query = Query::from_http("name=contains:john&age=between:20,30".to_string()).unwrap();
 
     // Test accessing parsed parameters using the trait methods
-    let name_param = query.parameters.inner().get("name").unwrap();
+    let name_param = &query.parameters.inner()["name"][0];
     assert_eq!(name_param.similarity(), &Similarity::Contains);
     assert_eq!(name_param.values(), &vec!["john".to_string()]);
 
-    let age_param = query.parameters.inner().get("age").unwrap();
+    let age_param = &query.parameters.inner()["age"][0];
     assert_eq!(age_param.similarity(), &Similarity::Between);
     assert_eq!(
         age_param.values(),
@@ -591,18 +605,18 @@ fn test_all_new_features_roundtrip() {
     assert_eq!(reconstructed.offset, 10);
 
     // Verify parameter access using trait methods
-    let name_param = reconstructed.parameters.inner().get("name").unwrap();
+    let name_param = &reconstructed.parameters.inner()["name"][0];
     assert_eq!(name_param.similarity(), &Similarity::Equals);
     assert_eq!(
         name_param.values(),
         &vec!["john".to_string(), "jane".to_string()]
     );
 
-    let desc_param = reconstructed.parameters.inner().get("description").unwrap();
+    let desc_param = &reconstructed.parameters.inner()["description"][0];
     assert_eq!(desc_param.similarity(), &Similarity::Contains);
     assert_eq!(desc_param.values(), &vec!["rust".to_string()]);
 
-    let age_param = reconstructed.parameters.inner().get("age").unwrap();
+    let age_param = &reconstructed.parameters.inner()["age"][0];
     assert_eq!(age_param.similarity(), &Similarity::Between);
     assert_eq!(
         age_param.values(),
@@ -628,12 +642,12 @@ fn test_query_from_http_normal_parameters() {
     assert_eq!(query.parameters.inner().len(), 2);
 
     assert!(query.parameters.inner().contains_key("name"));
-    let param = &query.parameters.inner()["name"];
+    let param = &query.parameters.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["ben"]);
 
     assert!(query.parameters.inner().contains_key("age"));
-    let param = &query.parameters.inner()["age"];
+    let param = &query.parameters.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["20"]);
 }
@@ -646,7 +660,7 @@ fn test_query_from_http_repeated_parameters() {
     assert_eq!(query.parameters.inner().len(), 1);
 
     assert!(query.parameters.inner().contains_key("name"));
-    let param = &query.parameters.inner()["name"];
+    let param = &query.parameters.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["ben", "john", "alice"]);
 }
@@ -661,19 +675,19 @@ fn test_query_from_http_mixed_normal_and_similarity() {
 
     // Normal parameters (repeated)
     assert!(query.parameters.inner().contains_key("name"));
-    let param = &query.parameters.inner()["name"];
+    let param = &query.parameters.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["ben", "john"]);
 
     // Similarity-based parameter
     assert!(query.parameters.inner().contains_key("age"));
-    let param = &query.parameters.inner()["age"];
+    let param = &query.parameters.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), vec!["20"]);
 
     // Normal parameter (single)
     assert!(query.parameters.inner().contains_key("status"));
-    let param = &query.parameters.inner()["status"];
+    let param = &query.parameters.inner()["status"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["active"]);
 }
@@ -688,12 +702,12 @@ fn test_query_from_http_normal_with_special_params() {
     assert_eq!(query.parameters.inner().len(), 2);
 
     assert!(query.parameters.inner().contains_key("name"));
-    let param = &query.parameters.inner()["name"];
+    let param = &query.parameters.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["ben"]);
 
     assert!(query.parameters.inner().contains_key("age"));
-    let param = &query.parameters.inner()["age"];
+    let param = &query.parameters.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["20"]);
 
@@ -715,12 +729,12 @@ fn test_query_from_http_url_encoded_normal_params() {
     assert_eq!(query.parameters.inner().len(), 2);
 
     assert!(query.parameters.inner().contains_key("name"));
-    let param = &query.parameters.inner()["name"];
+    let param = &query.parameters.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["john doe"]);
 
     assert!(query.parameters.inner().contains_key("email"));
-    let param = &query.parameters.inner()["email"];
+    let param = &query.parameters.inner()["email"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["test@example.com"]);
 }
@@ -733,10 +747,57 @@ fn test_query_from_http_repeated_mixed_similarity() {
     assert_eq!(query.parameters.inner().len(), 1);
 
     assert!(query.parameters.inner().contains_key("name"));
-    let param = &query.parameters.inner()["name"];
-    // The similarity-based parameter takes precedence
-    assert_eq!(*param.similarity(), Similarity::Contains);
-    assert_eq!(*param.values(), vec!["john"]);
+    // Plain values merge into one equals condition, the similarity is ANDed on
+    assert_eq!(
+        query.parameters.inner()["name"],
+        vec![
+            Parameter::init(
+                Similarity::Equals,
+                vec!["ben".to_string(), "alice".to_string()]
+            ),
+            Parameter::init(Similarity::Contains, vec!["john".to_string()]),
+        ]
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_repeated_similarity_key() {
+    let query = Query::from_http("age=greater:18&age=lesser:65".to_string()).unwrap();
+
+    assert_eq!(query.parameters.inner().len(), 1);
+    let conditions = &query.parameters.inner()["age"];
+    assert_eq!(conditions.len(), 2);
+    assert_eq!(*conditions[0].similarity(), Similarity::Greater);
+    assert_eq!(*conditions[0].values(), vec!["18"]);
+    assert_eq!(*conditions[1].similarity(), Similarity::Lesser);
+    assert_eq!(*conditions[1].values(), vec!["65"]);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_plain_value_after_similarity() {
+    let query = Query::from_http("age=greater:18&age=30&age=40".to_string()).unwrap();
+
+    assert_eq!(
+        query.parameters.inner()["age"],
+        vec![
+            Parameter::init(Similarity::Greater, vec!["18".to_string()]),
+            Parameter::init(Similarity::Equals, vec!["30".to_string(), "40".to_string()]),
+        ]
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_roundtrip_repeated_similarity_key() {
+    let original = "age=greater:18&age=lesser:65&name=contains:jo";
+    let query = Query::from_http(original.to_string()).unwrap();
+    assert_eq!(
+        query.to_http(),
+        "age=greater:18&age=lesser:65&name=contains:jo&limit=50&offset=0"
+    );
+    assert_eq!(Query::from_http(query.to_http()).unwrap(), query);
 }
 
 #[cfg(feature = "http")]
@@ -747,7 +808,7 @@ fn test_query_from_http_empty_normal_values() {
     assert_eq!(query.parameters.inner().len(), 1);
 
     assert!(query.parameters.inner().contains_key("age"));
-    let param = &query.parameters.inner()["age"];
+    let param = &query.parameters.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["20"]);
 }
@@ -759,14 +820,14 @@ fn test_query_to_http_normal_parameters() {
 
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec!["ben".to_string(), "john".to_string()],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(Similarity::Equals, vec!["20".to_string()]),
+        vec![Parameter::init(Similarity::Equals, vec!["20".to_string()])],
     );
 
     let http = query.to_http();
@@ -807,3 +868,336 @@ fn test_query_roundtrip_mixed_normal_and_similarity() {
     assert!(reconstructed.contains("offset=0"));
 }
 
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_with_or_group() {
+    let query = Query::from_http(
+        "title=contains:rust&or=(status=equals:draft,owner=equals:me)&limit=10".to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(query.parameters.inner().len(), 1);
+    assert_eq!(
+        query.parameters.groups(),
+        &vec![Filter::Or(vec![
+            Filter::Condition(
+                "status".to_string(),
+                Parameter::init(Similarity::Equals, vec!["draft".to_string()]),
+            ),
+            Filter::Condition(
+                "owner".to_string(),
+                Parameter::init(Similarity::Equals, vec!["me".to_string()]),
+            ),
+        ])]
+    );
+    assert_eq!(query.limit, 10);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_invalid_group() {
+    let result = Query::from_http("or=(status=equals:draft".to_string());
+    assert!(matches!(result, Err(Error::InvalidFilter(_))));
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_roundtrip_groups() {
+    let original = "title=contains:rust&or=(status=equals:draft,and=(owner=equals:me,age=greater:18))&not=(archived=equals:1)&limit=50&offset=0";
+    let query = Query::from_http(original.to_string()).unwrap();
+    assert_eq!(query.to_http(), original);
+    assert_eq!(Query::from_http(query.to_http()).unwrap(), query);
+}
+
+#[cfg(all(feature = "http", feature = "sql"))]
+#[test]
+fn test_query_from_http_groups_to_sql() {
+    let query = Query::from_http(
+        "or=(status=equals:draft,owner=equals:me)&not=(archived=equals:1)".to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        query.to_sql(),
+        "WHERE (\"status\" = ? OR \"owner\" = ?) AND NOT (\"archived\" = ?) LIMIT ? OFFSET ?"
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_negated_similarities() {
+    let original =
+        "status=not-equals:archived&name=not-contains:test&age=not-between:20,30&limit=50&offset=0";
+    let query = Query::from_http(original.to_string()).unwrap();
+
+    assert_eq!(
+        *query.parameters.inner()["status"][0].similarity(),
+        Similarity::NotEquals
+    );
+    assert_eq!(
+        *query.parameters.inner()["name"][0].similarity(),
+        Similarity::NotContains
+    );
+    assert_eq!(
+        *query.parameters.inner()["age"][0].similarity(),
+        Similarity::NotBetween
+    );
+    assert_eq!(query.to_http(), original);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_null_checks() {
+    let original = "deleted_at=is-null:&email=is-not-null:&limit=50&offset=0";
+    let query = Query::from_http(original.to_string()).unwrap();
+
+    assert_eq!(query.parameters.inner().len(), 2);
+    assert_eq!(
+        *query.parameters.inner()["deleted_at"][0].similarity(),
+        Similarity::IsNull
+    );
+    assert_eq!(
+        *query.parameters.inner()["email"][0].similarity(),
+        Similarity::IsNotNull
+    );
+    assert_eq!(query.to_http(), original);
+
+    assert!(Query::from_http("deleted_at=is-null:2025".to_string()).is_err());
+
+    let query =
+        Query::from_http("or=(deleted_at=is-null:,status=equals:draft)".to_string()).unwrap();
+    assert_eq!(
+        query.to_http(),
+        "or=(deleted_at=is-null:,status=equals:draft)&limit=50&offset=0"
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_keeps_null_as_text() {
+    // Parsing never interprets "null"; only the renderer's sentinel does
+    let query = Query::from_http("name=null".to_string()).unwrap();
+    assert_eq!(*query.parameters.inner()["name"][0].values(), vec!["null"]);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_raw_like() {
+    let original = "code=like:A_B%25&limit=50&offset=0";
+    let query = Query::from_http(original.to_string()).unwrap();
+
+    assert_eq!(
+        *query.parameters.inner()["code"][0].similarity(),
+        Similarity::Like
+    );
+    assert_eq!(*query.parameters.inner()["code"][0].values(), vec!["A_B%"]);
+    assert_eq!(query.to_http(), original);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_case_insensitive() {
+    let original = "name=equals-ci:john&email=contains-ci:GMAIL&limit=50&offset=0";
+    let query = Query::from_http(original.to_string()).unwrap();
+
+    assert_eq!(
+        *query.parameters.inner()["name"][0].case(),
+        Case::Insensitive
+    );
+    assert_eq!(
+        *query.parameters.inner()["email"][0].case(),
+        Case::Insensitive
+    );
+    assert_eq!(query.to_http(), original);
+
+    // Plain values are only merged into a case-sensitive equals condition
+    let query = Query::from_http("name=equals-ci:john&name=jane".to_string()).unwrap();
+    assert_eq!(
+        query.to_http(),
+        "name=equals-ci:john&name=equals:jane&limit=50&offset=0"
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_cursor_http_round_trip() {
+    let mut query = Query::new();
+    query
+        .order
+        .descending("created".to_string())
+        .ascending("id".to_string());
+    query.limit = 20;
+    query.cursor = Some(Cursor::new(vec![
+        "2025-01-01 10:00:00".to_string(),
+        "7".to_string(),
+    ]));
+
+    let http = query.to_http();
+    let token = query.cursor.as_ref().unwrap().to_string();
+    // The cursor replaces the offset
+    assert_eq!(
+        http,
+        format!("order=created:desc,id:asc&cursor={}&limit=20", token)
+    );
+    assert_eq!(Query::from_http(http).unwrap(), query);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_fields_http_round_trip() {
+    let query =
+        Query::from_http("name=john&fields=id,name,email&order=name:asc".to_string()).unwrap();
+
+    // `fields` is not parsed as a filter
+    assert_eq!(query.parameters.inner().len(), 1);
+    assert_eq!(
+        query.fields.inner().iter().collect::<Vec<_>>(),
+        vec!["id", "name", "email"]
+    );
+
+    let http = query.to_http();
+    assert_eq!(
+        http,
+        "name=equals:john&order=name:asc&fields=id,name,email&limit=50&offset=0"
+    );
+    assert_eq!(Query::from_http(http).unwrap(), query);
+
+    // No fields, no `fields=`
+    assert_eq!(Query::new().to_http(), "limit=50&offset=0");
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_query_from_http_invalid_cursor() {
+    assert_eq!(
+        Query::from_http("order=id:asc&cursor=not*a*token".to_string()),
+        Err(Error::InvalidCursor("not*a*token".to_string()))
+    );
+    // An empty cursor is ignored like other empty values
+    assert_eq!(
+        Query::from_http("cursor=".to_string()).unwrap().cursor,
+        None
+    );
+
+    // A cursor needs one value per order field, wherever the order appears
+    let token = Cursor::new(vec!["7".to_string()]).to_string();
+    for search in [
+        format!("tenant=equals:7&cursor={token}"),
+        format!("cursor={token}&order=created:desc,id:asc"),
+    ] {
+        assert_eq!(
+            Query::from_http(search),
+            Err(Error::InvalidCursor(token.clone()))
+        );
+    }
+    assert!(Query::from_http(format!("cursor={token}&order=id:asc")).is_ok());
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_parse_config_default_matches_from_http() {
+    let config = ParseConfig::default();
+    let query = Query::from_http_with_config("limit=abc&offset=-3".to_string(), &config).unwrap();
+    assert_eq!(query.limit, Parameters::DEFAULT_LIMIT);
+    assert_eq!(query.offset, Parameters::DEFAULT_OFFSET);
+
+    let query = Query::from_http_with_config("limit=100000000".to_string(), &config).unwrap();
+    assert_eq!(query.limit, 100000000);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_parse_config_lenient_clamps() {
+    let config = ParseConfig::new().max_limit(100).max_offset(1000);
+
+    let query =
+        Query::from_http_with_config("limit=5000&offset=99999".to_string(), &config).unwrap();
+    assert_eq!(query.limit, 100);
+    assert_eq!(query.offset, 1000);
+
+    let query =
+        Query::from_http_with_config("limit=99999999999999999999999".to_string(), &config).unwrap();
+    assert_eq!(query.limit, 100);
+
+    let query = Query::from_http_with_config("limit=abc&offset=xyz".to_string(), &config).unwrap();
+    assert_eq!(query.limit, Parameters::DEFAULT_LIMIT);
+    assert_eq!(query.offset, 0);
+
+    let query = Query::from_http_with_config("limit=100&offset=1000".to_string(), &config).unwrap();
+    assert_eq!(query.limit, 100);
+    assert_eq!(query.offset, 1000);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_parse_config_default_limit() {
+    let config = ParseConfig::new().default_limit(20);
+    assert_eq!(
+        Query::from_http_with_config(String::new(), &config)
+            .unwrap()
+            .limit,
+        20
+    );
+    assert_eq!(
+        Query::from_http_with_config("limit=abc".to_string(), &config)
+            .unwrap()
+            .limit,
+        20
+    );
+
+    // The default never exceeds the maximum
+    let config = ParseConfig::new().default_limit(500).max_limit(100);
+    assert_eq!(config.initial_limit(), 100);
+    assert_eq!(
+        Query::from_http_with_config("name=john".to_string(), &config)
+            .unwrap()
+            .limit,
+        100
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_parse_config_strict_rejects() {
+    let config = ParseConfig::new()
+        .max_limit(100)
+        .max_offset(1000)
+        .policy(Policy::Strict);
+
+    assert_eq!(
+        Query::from_http_with_config("limit=101".to_string(), &config),
+        Err(Error::InvalidLimit("101".to_string()))
+    );
+    assert_eq!(
+        Query::from_http_with_config("limit=abc".to_string(), &config),
+        Err(Error::InvalidLimit("abc".to_string()))
+    );
+    assert_eq!(
+        Query::from_http_with_config("offset=1001".to_string(), &config),
+        Err(Error::InvalidOffset("1001".to_string()))
+    );
+    assert_eq!(
+        Query::from_http_with_config("offset=-1".to_string(), &config),
+        Err(Error::InvalidOffset("-1".to_string()))
+    );
+
+    let query = Query::from_http_with_config("limit=100&offset=1000".to_string(), &config).unwrap();
+    assert_eq!(query.limit, 100);
+    assert_eq!(query.offset, 1000);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_parse_config_strict_without_maximum() {
+    let config = ParseConfig::new().policy(Policy::Strict);
+    assert_eq!(
+        Query::from_http_with_config("limit=100000000".to_string(), &config)
+            .unwrap()
+            .limit,
+        100000000
+    );
+    assert_eq!(
+        Query::from_http_with_config("limit=99999999999999999999999".to_string(), &config),
+        Err(Error::InvalidLimit("99999999999999999999999".to_string()))
+    );
+}
@@ -0,0 +1,374 @@
+#![cfg(feature = "json")]
+
+use query_lite::error::Error;
+use query_lite::*;
+
+// ============================================================================
+// JSON BODY TESTS
+// ============================================================================
+
+fn json(body: &str) -> Query {
+    Query::from_json(body.to_string()).unwrap()
+}
+
+#[test]
+fn test_json_empty() {
+    let query = json("{}");
+    assert_eq!(query, Query::new());
+    assert_eq!(query.to_json(), r#"{"limit":50,"offset":0}"#);
+}
+
+#[test]
+fn test_json_filter() {
+    let query = json(
+        r#"{
+            "filter": {
+                "name": {"contains": ["jo"]},
+                "age": {"greater": 18, "lesser": 65},
+                "status": ["active", "pending"],
+                "email": {"ends-with-ci": "@EXAMPLE.COM"},
+                "deleted": {"is-null": []}
+            },
+            "order": ["date_created:desc", "name:asc"],
+            "fields": ["id", "name"],
+            "limit": 20,
+            "offset": 40
+        }"#,
+    );
+
+    let age = vec![
+        Parameter::init(Similarity::Greater, vec!["18".to_string()]),
+        Parameter::init(Similarity::Lesser, vec!["65".to_string()]),
+    ];
+
+    let parameters = query.parameters.inner();
+    assert_eq!(
+        parameters.keys().collect::<Vec<_>>(),
+        vec!["name", "age", "status", "email", "deleted"]
+    );
+    assert_eq!(
+        parameters["name"],
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["jo".to_string()]
+        )]
+    );
+    assert_eq!(parameters["age"], age);
+    assert_eq!(
+        parameters["status"],
+        vec![Parameter::init(
+            Similarity::Equals,
+            vec!["active".to_string(), "pending".to_string()]
+        )]
+    );
+    assert_eq!(
+        parameters["email"],
+        vec![
+            Parameter::init(Similarity::EndsWith, vec!["@EXAMPLE.COM".to_string()])
+                .with_case(Case::Insensitive)
+        ]
+    );
+    assert_eq!(
+        parameters["deleted"],
+        vec![Parameter::init(Similarity::IsNull, vec![])]
+    );
+    assert_eq!(
+        query.order.inner().keys().collect::<Vec<_>>(),
+        vec!["date_created", "name"]
+    );
+    assert_eq!(
+        query.order.inner()["date_created"],
+        SortDirection::Descending
+    );
+    assert_eq!(query.fields.inner().len(), 2);
+    assert_eq!(query.limit, 20);
+    assert_eq!(query.offset, 40);
+}
+
+#[test]
+fn test_json_scalar_values() {
+    let query =
+        json(r#"{"filter": {"score": {"equals": [7.5, 9]}, "active": true, "owner": null}}"#);
+    let parameters = query.parameters.inner();
+    assert_eq!(
+        parameters["score"][0].values(),
+        &vec!["7.5".to_string(), "9".to_string()]
+    );
+    assert_eq!(parameters["active"][0].values(), &vec!["true".to_string()]);
+    // null is the null sentinel
+    assert_eq!(parameters["owner"][0].values(), &vec!["null".to_string()]);
+}
+
+#[test]
+fn test_json_skips_empty_conditions() {
+    let query = json(r#"{"filter": {"name": {"contains": []}, "tags": []}}"#);
+    assert!(query.parameters.inner().is_empty());
+}
+
+#[test]
+fn test_json_groups() {
+    let query = json(
+        r#"{"filter": {
+            "or": [
+                {"status": "draft"},
+                {"not": {"owner": {"equals": "me"}}},
+                {"age": {"greater": 18}, "verified": true}
+            ]
+        }}"#,
+    );
+    assert_eq!(
+        query.parameters.groups(),
+        &vec![Filter::Or(vec![
+            Filter::Condition(
+                "status".to_string(),
+                Parameter::init(Similarity::Equals, vec!["draft".to_string()])
+            ),
+            Filter::Not(Box::new(Filter::Condition(
+                "owner".to_string(),
+                Parameter::init(Similarity::Equals, vec!["me".to_string()])
+            ))),
+            Filter::And(vec![
+                Filter::Condition(
+                    "age".to_string(),
+                    Parameter::init(Similarity::Greater, vec!["18".to_string()])
+                ),
+                Filter::Condition(
+                    "verified".to_string(),
+                    Parameter::init(Similarity::Equals, vec!["true".to_string()])
+                ),
+            ]),
+        ])]
+    );
+}
+
+#[test]
+fn test_json_filter_list() {
+    // A list of objects reads like repeated keys, so a similarity or group
+    // can appear twice
+    let query = json(
+        r#"{"filter": [
+            {"age": {"greater": 18}, "or": [{"a": 1}, {"b": 2}]},
+            {"age": {"greater": 21}, "or": [{"c": 3}, {"d": 4}]}
+        ]}"#,
+    );
+    assert_eq!(query.parameters.inner()["age"].len(), 2);
+    assert_eq!(query.parameters.groups().len(), 2);
+
+    let same = json(r#"{"filter": {"age": [{"greater": 18}, {"greater": 21}]}}"#);
+    assert_eq!(same.parameters.inner(), query.parameters.inner());
+
+    // Repeated plain values join one equals condition, meaning `IN`
+    let query = json(r#"{"filter": [{"name": "john"}, {"name": "doe"}]}"#);
+    assert_eq!(
+        query.parameters.inner()["name"][0],
+        Parameter::init(
+            Similarity::Equals,
+            vec!["john".to_string(), "doe".to_string()]
+        )
+    );
+}
+
+#[test]
+fn test_json_cursor() {
+    let mut query = Query::new();
+    query
+        .order
+        .descending("score".to_string())
+        .ascending("id".to_string());
+    query.cursor = Some(Cursor::new(vec!["90".to_string(), "7".to_string()]));
+    query.limit = 10;
+
+    let body = query.to_json();
+    assert!(!body.contains("offset"));
+    assert_eq!(Query::from_json(body).unwrap(), query);
+
+    assert_eq!(json(r#"{"cursor": null}"#).cursor, None);
+}
+
+#[test]
+fn test_json_to_json() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .contains("name".to_string(), vec!["jo".to_string()])
+        .is_null("deleted".to_string());
+    query.order.descending("date_created".to_string());
+    query.limit = 20;
+    assert_eq!(
+        query.to_json(),
+        r#"{"filter":{"name":{"contains":["jo"]},"deleted":{"is-null":[]}},"order":["date_created:desc"],"limit":20,"offset":0}"#
+    );
+}
+
+#[test]
+fn test_json_round_trip() {
+    let age = vec![
+        Parameter::init(Similarity::Greater, vec!["18".to_string()]),
+        Parameter::init(Similarity::Greater, vec!["21".to_string()]),
+        Parameter::init(Similarity::LesserOrEqual, vec!["65".to_string()]),
+    ];
+
+    let mut query = Query::new();
+    query.parameters.inner_mut().insert("age".to_string(), age);
+    query.parameters.inner_mut().insert(
+        "name".to_string(),
+        vec![
+            Parameter::init(Similarity::Contains, vec!["JO".to_string()])
+                .with_case(Case::Insensitive),
+        ],
+    );
+    let status = |value: &str| {
+        Filter::Condition(
+            "status".to_string(),
+            Parameter::init(Similarity::Equals, vec![value.to_string()]),
+        )
+    };
+    query
+        .parameters
+        .group(Filter::Or(vec![status("draft"), status("review")]))
+        .group(Filter::Or(vec![status("a"), status("b")]))
+        .group(Filter::Not(Box::new(Filter::And(vec![
+            status("c"),
+            status("d"),
+        ]))));
+    query.fields.add("id".to_string());
+    query.offset = 100;
+
+    assert_eq!(Query::from_json(query.to_json()).unwrap(), query);
+}
+
+#[test]
+fn test_json_errors() {
+    let error = |body: &str| Query::from_json(body.to_string()).unwrap_err();
+
+    assert!(matches!(error("{"), Error::InvalidJson(_)));
+    assert!(matches!(error("[]"), Error::InvalidJson(_)));
+    assert_eq!(
+        error(r#"{"limt": 10}"#),
+        Error::InvalidJson("unknown key 'limt'".to_string())
+    );
+    assert_eq!(
+        error(r#"{"filter": "name"}"#),
+        Error::InvalidJson("expected an object or a list of objects for 'filter'".to_string())
+    );
+    assert_eq!(
+        error(r#"{"filter": {"name": {"sort-of": "jo"}}}"#),
+        Error::InvalidSimilarity("sort-of".to_string())
+    );
+    assert_eq!(
+        error(r#"{"filter": {"name": {"contains": [["jo"]]}}}"#),
+        Error::InvalidJson("expected a string, number, boolean or null for 'name'".to_string())
+    );
+    assert_eq!(
+        error(r#"{"filter": {"deleted": {"is-null": ["x"]}}}"#),
+        Error::InvalidParameter("deleted: is-null".to_string())
+    );
+    assert!(matches!(
+        error(r#"{"filter": {"or": [{}]}}"#),
+        Error::InvalidFilter(_)
+    ));
+    assert!(matches!(
+        error(r#"{"filter": {"or": {"a": 1}}}"#),
+        Error::InvalidJson(_)
+    ));
+    assert_eq!(
+        error(r#"{"order": ["name"]}"#),
+        Error::InvalidOrderField("name".to_string())
+    );
+    assert_eq!(
+        error(r#"{"order": ["name:up"]}"#),
+        Error::InvalidSortDirection("up".to_string())
+    );
+    assert!(matches!(
+        error(r#"{"cursor": "!!"}"#),
+        Error::InvalidCursor(_)
+    ));
+    let token = Cursor::new(vec!["7".to_string()]).to_string();
+    assert_eq!(
+        error(&format!(
+            r#"{{"cursor": "{token}", "order": ["a:asc", "b:asc"]}}"#
+        )),
+        Error::InvalidCursor(token)
+    );
+}
+
+#[test]
+fn test_json_config() {
+    let config = ParseConfig::new().max_limit(100);
+    let query = Query::from_json_with_config(r#"{"limit": 500}"#.to_string(), &config).unwrap();
+    assert_eq!(query.limit, 100);
+
+    // Malformed values fall back to the defaults, as in from_http
+    assert_eq!(json(r#"{"limit": -1, "offset": "abc"}"#), Query::new());
+
+    let strict = config.policy(Policy::Strict);
+    assert_eq!(
+        Query::from_json_with_config(r#"{"limit": 500}"#.to_string(), &strict),
+        Err(Error::InvalidLimit("500".to_string()))
+    );
+    assert_eq!(
+        Query::from_json_with_config(r#"{"offset": 1.5}"#.to_string(), &strict),
+        Err(Error::InvalidOffset("1.5".to_string()))
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_json_matches_from_http() {
+    let cases = [
+        (
+            r#"{"filter": {"name": {"contains": ["jo"]}}, "order": ["date_created:desc"], "limit": 20}"#,
+            "name=contains:jo&order=date_created:desc&limit=20",
+        ),
+        (
+            r#"{"filter": {"name": ["ben", "john"], "age": 20}}"#,
+            "name=ben&name=john&age=20",
+        ),
+        (
+            r#"{"filter": {"age": {"greater": 18, "lesser": 65}, "email": {"ends-with-ci": "@x.com"}}}"#,
+            "age=greater:18&age=lesser:65&email=ends-with-ci:@x.com",
+        ),
+        (
+            r#"{"filter": {"deleted": {"is-null": []}, "owner": {"not-equals": [null, "bob"]}}}"#,
+            "deleted=is-null:&owner=not-equals:null,bob",
+        ),
+        (
+            r#"{"filter": {"or": [{"age": {"lesser": 18}}, {"not": {"status": {"equals": "active"}}}]}, "fields": ["id", "name"]}"#,
+            "or=(age=lesser:18,not=(status=equals:active))&fields=id,name",
+        ),
+        (
+            r#"{"filter": {"not": {"a": 1, "b": 2}}}"#,
+            "not=(a=equals:1,b=equals:2)",
+        ),
+        (
+            r#"{"filter": [{"name": "john"}, {"name": "doe"}]}"#,
+            "name=john&name=doe",
+        ),
+        (
+            r#"{"filter": [{"name": {"equals": "john"}}, {"name": ["doe", "jane"]}, {"name": {"equals-ci": "x"}}]}"#,
+            "name=equals:john&name=doe&name=jane&name=equals-ci:x",
+        ),
+        (
+            r#"{"filter": {"created": {"between": ["2025-01-01T10:00", "2025-01-01T18:00"]}}, "offset": 30}"#,
+            "created=between:2025-01-01T10:00,2025-01-01T18:00&offset=30",
+        ),
+    ];
+    for (body, search) in cases {
+        let from_json = Query::from_json(body.to_string()).unwrap();
+        let from_http = Query::from_http(search.to_string()).unwrap();
+        assert_eq!(from_json, from_http, "{}", search);
+        assert_eq!(
+            Query::from_json(from_http.to_json()).unwrap(),
+            from_http,
+            "{}",
+            search
+        );
+    }
+
+    let mut query = Query::from_http("order=score:desc,id:asc&limit=5".to_string()).unwrap();
+    query.cursor = Some(Cursor::new(vec!["9".to_string(), "2".to_string()]));
+    assert_eq!(
+        Query::from_json(query.to_json()).unwrap(),
+        Query::from_http(query.to_http()).unwrap()
+    );
+}
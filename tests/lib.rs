@@ -538,6 +538,127 @@ fn test_similarity_display() {
     );
 }
 
+#[test]
+fn test_similarity_negated_constants() {
+    assert_eq!(Similarity::NOT_EQUALS, "not-equals");
+    assert_eq!(Similarity::NOT_CONTAINS, "not-contains");
+    assert_eq!(Similarity::NOT_STARTS_WITH, "not-starts-with");
+    assert_eq!(Similarity::NOT_ENDS_WITH, "not-ends-with");
+    assert_eq!(Similarity::NOT_BETWEEN, "not-between");
+}
+
+#[test]
+fn test_similarity_negated_from_str_and_display() {
+    let negated = [
+        ("not-equals", Similarity::NotEquals),
+        ("not-contains", Similarity::NotContains),
+        ("not-starts-with", Similarity::NotStartsWith),
+        ("not-ends-with", Similarity::NotEndsWith),
+        ("not-between", Similarity::NotBetween),
+    ];
+    for (s, similarity) in negated {
+        assert_eq!(Similarity::from_str(s).unwrap(), similarity);
+        assert_eq!(format!("{}", similarity), s);
+    }
+
+    assert!(Similarity::from_str("not_equals").is_err());
+    assert!(Similarity::from_str("not-lesser").is_err());
+    assert!(Similarity::from_str("NOT-EQUALS").is_err());
+}
+
+#[test]
+fn test_parse_parameter_negated() {
+    let param = "not-equals:archived,deleted".parse::<Parameter>().unwrap();
+    assert_eq!(*param.similarity(), Similarity::NotEquals);
+    assert_eq!(*param.values(), vec!["archived", "deleted"]);
+    assert_eq!(format!("{}", param), "not-equals:archived,deleted");
+}
+
+#[test]
+fn test_similarity_like() {
+    assert_eq!(Similarity::LIKE, "like");
+    assert_eq!(Similarity::from_str("like").unwrap(), Similarity::Like);
+    assert_eq!(format!("{}", Similarity::Like), "like");
+
+    let param = "like:a%25b_".parse::<Parameter>().unwrap();
+    assert_eq!(*param.similarity(), Similarity::Like);
+    assert_eq!(*param.values(), vec!["a%b_"]);
+}
+
+#[test]
+fn test_parse_parameter_case_insensitive() {
+    let param = "equals-ci:John".parse::<Parameter>().unwrap();
+    assert_eq!(*param.similarity(), Similarity::Equals);
+    assert_eq!(*param.case(), Case::Insensitive);
+    assert_eq!(*param.values(), vec!["John"]);
+    assert_eq!(format!("{}", param), "equals-ci:John");
+
+    let param = "not-contains-ci:jo".parse::<Parameter>().unwrap();
+    assert_eq!(*param.similarity(), Similarity::NotContains);
+    assert_eq!(*param.case(), Case::Insensitive);
+
+    let param = "equals:John".parse::<Parameter>().unwrap();
+    assert_eq!(*param.case(), Case::Sensitive);
+    assert_eq!(format!("{}", param), "equals:John");
+
+    assert!("equals-CI:John".parse::<Parameter>().is_err());
+    assert!("-ci:John".parse::<Parameter>().is_err());
+    assert!("is-null-ci:".parse::<Parameter>().is_err());
+}
+
+#[test]
+fn test_parameter_with_case() {
+    assert_eq!(Case::default(), Case::Sensitive);
+    assert_eq!(Case::INSENSITIVE_SUFFIX, "-ci");
+
+    let param = Parameter::init(Similarity::Greater, vec!["m".to_string()]);
+    assert_eq!(*param.case(), Case::Sensitive);
+    let param =
+        Parameter::init(Similarity::Contains, vec!["jo".to_string()]).with_case(Case::Insensitive);
+    assert_eq!(*param.case(), Case::Insensitive);
+    assert_ne!(
+        Parameter::init(Similarity::Equals, vec!["a".to_string()]),
+        Parameter::init(Similarity::Equals, vec!["a".to_string()]).with_case(Case::Insensitive)
+    );
+}
+
+#[test]
+fn test_similarity_null_checks() {
+    assert_eq!(Similarity::IS_NULL, "is-null");
+    assert_eq!(Similarity::IS_NOT_NULL, "is-not-null");
+    assert_eq!(Similarity::from_str("is-null").unwrap(), Similarity::IsNull);
+    assert_eq!(
+        Similarity::from_str("is-not-null").unwrap(),
+        Similarity::IsNotNull
+    );
+    assert_eq!(format!("{}", Similarity::IsNull), "is-null");
+    assert_eq!(format!("{}", Similarity::IsNotNull), "is-not-null");
+
+    assert!(!Similarity::IsNull.takes_values());
+    assert!(!Similarity::IsNotNull.takes_values());
+    assert!(Similarity::Equals.takes_values());
+    assert!(Similarity::NotBetween.takes_values());
+}
+
+#[test]
+fn test_parse_parameter_null_checks() {
+    let param = "is-null:".parse::<Parameter>().unwrap();
+    assert_eq!(*param.similarity(), Similarity::IsNull);
+    assert!(param.values().is_empty());
+    assert!(!param.is_empty());
+    assert_eq!(format!("{}", param), "is-null:");
+
+    let param = "is-not-null:".parse::<Parameter>().unwrap();
+    assert_eq!(*param.similarity(), Similarity::IsNotNull);
+    assert_eq!(format!("{}", param), "is-not-null:");
+
+    // Null checks take no values
+    assert!("is-null:foo".parse::<Parameter>().is_err());
+    assert!("is-null".parse::<Parameter>().is_err());
+
+    assert!("equals:".parse::<Parameter>().unwrap().is_empty());
+}
+
 // ============================================================================
 // PARAMETER TESTS
 // ============================================================================
@@ -626,8 +747,19 @@ fn test_parse_parameter_invalid_no_colon() {
 }
 
 #[test]
-fn test_parse_parameter_invalid_multiple_colons() {
-    assert!("contains:damian:extra".parse::<Parameter>().is_err());
+fn test_parse_parameter_multiple_colons() {
+    // Only the first colon separates the similarity, so times need no encoding
+    let param = "contains:damian:extra".parse::<Parameter>().unwrap();
+    assert_eq!(*param.values(), vec!["damian:extra"]);
+
+    let param = "between:2025-01-01T10:00:00,2025-01-01T18:30:00"
+        .parse::<Parameter>()
+        .unwrap();
+    assert_eq!(*param.similarity(), Similarity::Between);
+    assert_eq!(
+        *param.values(),
+        vec!["2025-01-01T10:00:00", "2025-01-01T18:30:00"]
+    );
 }
 
 #[test]
@@ -744,7 +876,7 @@ fn test_parameters_builder_equals() {
 
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("name"));
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), values);
 }
@@ -757,7 +889,7 @@ fn test_parameters_builder_contains() {
 
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("name"));
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), values);
 }
@@ -770,7 +902,7 @@ fn test_parameters_builder_starts_with() {
 
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("name"));
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::StartsWith);
     assert_eq!(*param.values(), values);
 }
@@ -783,7 +915,7 @@ fn test_parameters_builder_ends_with() {
 
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("name"));
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::EndsWith);
     assert_eq!(*param.values(), values);
 }
@@ -796,7 +928,7 @@ fn test_parameters_builder_between() {
 
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("age"));
-    let param = &params.inner()["age"];
+    let param = &params.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), values);
 }
@@ -809,7 +941,7 @@ fn test_parameters_builder_lesser() {
 
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("price"));
-    let param = &params.inner()["price"];
+    let param = &params.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::Lesser);
     assert_eq!(*param.values(), values);
 }
@@ -822,7 +954,7 @@ fn test_parameters_builder_lesser_or_equal() {
 
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("price"));
-    let param = &params.inner()["price"];
+    let param = &params.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::LesserOrEqual);
     assert_eq!(*param.values(), values);
 }
@@ -835,7 +967,7 @@ fn test_parameters_builder_greater() {
 
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("price"));
-    let param = &params.inner()["price"];
+    let param = &params.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::Greater);
     assert_eq!(*param.values(), values);
 }
@@ -848,11 +980,69 @@ fn test_parameters_builder_greater_or_equal() {
 
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("price"));
-    let param = &params.inner()["price"];
+    let param = &params.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::GreaterOrEqual);
     assert_eq!(*param.values(), values);
 }
 
+#[test]
+fn test_parameters_builder_null_checks() {
+    let mut params = Parameters::new();
+    params
+        .is_null("deleted_at".to_string())
+        .is_not_null("email".to_string());
+
+    assert_eq!(
+        *params.inner()["deleted_at"][0].similarity(),
+        Similarity::IsNull
+    );
+    assert_eq!(
+        *params.inner()["email"][0].similarity(),
+        Similarity::IsNotNull
+    );
+    assert_eq!(params.to_string(), "deleted_at=is-null:&email=is-not-null:");
+    assert_eq!(
+        "deleted_at=is-null:&email=is-not-null:&name=equals:"
+            .parse::<Parameters>()
+            .unwrap(),
+        params
+    );
+}
+
+#[test]
+fn test_parameters_builder_negated() {
+    let mut params = Parameters::new();
+    params
+        .not_equals("status".to_string(), vec!["archived".to_string()])
+        .not_contains("name".to_string(), vec!["test".to_string()])
+        .not_starts_with("code".to_string(), vec!["tmp".to_string()])
+        .not_ends_with("email".to_string(), vec!["@spam.com".to_string()])
+        .not_between("age".to_string(), vec!["20".to_string(), "30".to_string()]);
+
+    assert_eq!(params.inner().len(), 5);
+    assert_eq!(
+        *params.inner()["status"][0].similarity(),
+        Similarity::NotEquals
+    );
+    assert_eq!(
+        *params.inner()["name"][0].similarity(),
+        Similarity::NotContains
+    );
+    assert_eq!(
+        *params.inner()["code"][0].similarity(),
+        Similarity::NotStartsWith
+    );
+    assert_eq!(
+        *params.inner()["email"][0].similarity(),
+        Similarity::NotEndsWith
+    );
+    assert_eq!(
+        *params.inner()["age"][0].similarity(),
+        Similarity::NotBetween
+    );
+    assert_eq!(*params.inner()["age"][0].values(), vec!["20", "30"]);
+}
+
 #[test]
 fn test_parameters_builder_fluent_api() {
     let mut params = Parameters::new();
@@ -863,15 +1053,15 @@ fn test_parameters_builder_fluent_api() {
 
     assert_eq!(params.inner().len(), 3);
 
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["damian"]);
 
-    let param = &params.inner()["surname"];
+    let param = &params.inner()["surname"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), vec!["black"]);
 
-    let param = &params.inner()["age"];
+    let param = &params.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), vec!["20", "30"]);
 }
@@ -883,7 +1073,7 @@ fn test_parameters_builder_overwrite() {
     params.contains("name".to_string(), vec!["john".to_string()]); // Should overwrite
 
     assert_eq!(params.inner().len(), 1);
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), vec!["john"]);
 }
@@ -894,11 +1084,67 @@ fn test_parameters_builder_empty_values() {
     params.equals("name".to_string(), vec![]);
 
     assert_eq!(params.inner().len(), 1);
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec![] as Vec<String>);
 }
 
+#[test]
+fn test_parameters_append() {
+    let mut params = Parameters::new();
+    params
+        .greater("age".to_string(), vec!["18".to_string()])
+        .append(
+            "age".to_string(),
+            Parameter::init(Similarity::Lesser, vec!["65".to_string()]),
+        )
+        .append(
+            "name".to_string(),
+            Parameter::init(Similarity::Contains, vec!["jo".to_string()]),
+        );
+
+    assert_eq!(params.inner().len(), 2);
+    assert_eq!(
+        params.inner()["age"],
+        vec![
+            Parameter::init(Similarity::Greater, vec!["18".to_string()]),
+            Parameter::init(Similarity::Lesser, vec!["65".to_string()]),
+        ]
+    );
+    assert_eq!(params.inner()["name"].len(), 1);
+
+    // The similarity builders replace every condition on the key
+    params.equals("age".to_string(), vec!["30".to_string()]);
+    assert_eq!(
+        params.inner()["age"],
+        vec![Parameter::init(Similarity::Equals, vec!["30".to_string()])]
+    );
+}
+
+#[test]
+fn test_parameters_display_repeated_key() {
+    let mut params = Parameters::new();
+    params
+        .greater("age".to_string(), vec!["18".to_string()])
+        .append(
+            "age".to_string(),
+            Parameter::init(Similarity::Lesser, vec!["65".to_string()]),
+        );
+
+    assert_eq!(format!("{}", params), "age=greater:18&age=lesser:65");
+}
+
+#[test]
+fn test_parameters_from_str_repeated_key() {
+    let params = "age=greater:18&age=lesser:65"
+        .parse::<Parameters>()
+        .unwrap();
+
+    assert_eq!(params.inner().len(), 1);
+    assert_eq!(params.inner()["age"].len(), 2);
+    assert_eq!(format!("{}", params), "age=greater:18&age=lesser:65");
+}
+
 // ============================================================================
 // PARAMETERS TESTS
 // ============================================================================
@@ -914,6 +1160,10 @@ fn test_parameters_constants() {
     assert!(Parameters::EXCLUDE.contains(&"order"));
     assert!(Parameters::EXCLUDE.contains(&"limit"));
     assert!(Parameters::EXCLUDE.contains(&"offset"));
+    assert!(Parameters::EXCLUDE.contains(&"cursor"));
+    assert!(Parameters::EXCLUDE.contains(&"fields"));
+    assert_eq!(Parameters::CURSOR, "cursor");
+    assert_eq!(Parameters::FIELDS, "fields");
 }
 
 #[test]
@@ -942,7 +1192,7 @@ fn test_parameters_from_str_single() {
     let params = Parameters::from_str("name=contains:damian").unwrap();
     assert_eq!(params.inner().len(), 1);
     assert!(params.inner().contains_key("name"));
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), vec!["damian"]);
 }
@@ -954,12 +1204,12 @@ fn test_parameters_from_str_multiple() {
     assert_eq!(params.inner().len(), 2);
 
     assert!(params.inner().contains_key("name"));
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), vec!["damian"]);
 
     assert!(params.inner().contains_key("surname"));
-    let param = &params.inner()["surname"];
+    let param = &params.inner()["surname"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["black", "steel", "wood"]);
 }
@@ -1014,17 +1264,17 @@ fn test_parameters_from_str_with_numeric_comparisons() {
     assert_eq!(params.inner().len(), 3);
 
     assert!(params.inner().contains_key("age"));
-    let param = &params.inner()["age"];
+    let param = &params.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), vec!["20", "30"]);
 
     assert!(params.inner().contains_key("price"));
-    let param = &params.inner()["price"];
+    let param = &params.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::Greater);
     assert_eq!(*param.values(), vec!["100"]);
 
     assert!(params.inner().contains_key("score"));
-    let param = &params.inner()["score"];
+    let param = &params.inner()["score"][0];
     assert_eq!(*param.similarity(), Similarity::LesserOrEqual);
     assert_eq!(*param.values(), vec!["85"]);
 }
@@ -1038,22 +1288,22 @@ fn test_parameters_from_str_mixed_similarity_types() {
     assert_eq!(params.inner().len(), 4);
 
     assert!(params.inner().contains_key("name"));
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), vec!["damian"]);
 
     assert!(params.inner().contains_key("age"));
-    let param = &params.inner()["age"];
+    let param = &params.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), vec!["25", "35"]);
 
     assert!(params.inner().contains_key("price"));
-    let param = &params.inner()["price"];
+    let param = &params.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::GreaterOrEqual);
     assert_eq!(*param.values(), vec!["50"]);
 
     assert!(params.inner().contains_key("status"));
-    let param = &params.inner()["status"];
+    let param = &params.inner()["status"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["active"]);
 }
@@ -1067,6 +1317,280 @@ fn test_parameters_from_str_numeric_with_whitespace() {
     assert!(params.inner().contains_key("price"));
 }
 
+// ============================================================================
+// FILTER TESTS
+// ============================================================================
+
+fn condition(key: &str, similarity: Similarity, values: &[&str]) -> Filter {
+    Filter::Condition(
+        key.to_string(),
+        Parameter::init(similarity, values.iter().map(|v| v.to_string()).collect()),
+    )
+}
+
+#[test]
+fn test_filter_constants() {
+    assert_eq!(Filter::AND, "and");
+    assert_eq!(Filter::OR, "or");
+    assert_eq!(Filter::NOT, "not");
+}
+
+#[test]
+fn test_filter_from_str_or() {
+    let filter = "or=(status=equals:draft,owner=equals:me)"
+        .parse::<Filter>()
+        .unwrap();
+    assert_eq!(
+        filter,
+        Filter::Or(vec![
+            condition("status", Similarity::Equals, &["draft"]),
+            condition("owner", Similarity::Equals, &["me"]),
+        ])
+    );
+}
+
+#[test]
+fn test_filter_from_str_nested() {
+    let filter =
+        "or=(status=equals:draft,published,and=(owner=me,age=greater:18),not=(archived=equals:1))"
+            .parse::<Filter>()
+            .unwrap();
+    assert_eq!(
+        filter,
+        Filter::Or(vec![
+            condition("status", Similarity::Equals, &["draft", "published"]),
+            Filter::And(vec![
+                condition("owner", Similarity::Equals, &["me"]),
+                condition("age", Similarity::Greater, &["18"]),
+            ]),
+            Filter::Not(Box::new(condition("archived", Similarity::Equals, &["1"]))),
+        ])
+    );
+}
+
+#[test]
+fn test_filter_from_str_not_multiple_items() {
+    let filter = "not=(a=equals:1,b=equals:2)".parse::<Filter>().unwrap();
+    assert_eq!(
+        filter,
+        Filter::Not(Box::new(Filter::And(vec![
+            condition("a", Similarity::Equals, &["1"]),
+            condition("b", Similarity::Equals, &["2"]),
+        ])))
+    );
+}
+
+#[test]
+fn test_filter_from_str_url_encoded_values() {
+    let filter = "or=(name=contains:a%2Cb,name=equals:%28x%29)"
+        .parse::<Filter>()
+        .unwrap();
+    assert_eq!(
+        filter,
+        Filter::Or(vec![
+            condition("name", Similarity::Contains, &["a,b"]),
+            condition("name", Similarity::Equals, &["(x)"]),
+        ])
+    );
+}
+
+#[test]
+fn test_filter_from_str_invalid() {
+    assert!(matches!(
+        "or=status=equals:draft".parse::<Filter>(),
+        Err(Error::InvalidFilter(_))
+    ));
+    assert!(matches!(
+        "or=(a=equals:1".parse::<Filter>(),
+        Err(Error::InvalidFilter(_))
+    ));
+    assert!(matches!(
+        "or=(a=equals:1))".parse::<Filter>(),
+        Err(Error::InvalidFilter(_))
+    ));
+    assert!(matches!(
+        "or=(draft,a=equals:1)".parse::<Filter>(),
+        Err(Error::InvalidFilter(_))
+    ));
+    assert!(matches!(
+        "or=".parse::<Filter>(),
+        Err(Error::InvalidFilter(_))
+    ));
+    assert!(matches!(
+        "or=(a=invalid:1)".parse::<Filter>(),
+        Err(Error::InvalidSimilarity(_))
+    ));
+}
+
+#[test]
+fn test_filter_display() {
+    let filter = Filter::Or(vec![
+        condition("status", Similarity::Equals, &["draft", "a,b"]),
+        Filter::Not(Box::new(Filter::And(vec![
+            condition("owner", Similarity::Equals, &["me"]),
+            condition("age", Similarity::Greater, &["18"]),
+        ]))),
+    ]);
+    let display = format!("{}", filter);
+    assert_eq!(
+        display,
+        "or=(status=equals:draft,a%2Cb,not=(and=(owner=equals:me,age=greater:18)))"
+    );
+    assert_eq!(display.parse::<Filter>().unwrap(), filter);
+}
+
+#[test]
+fn test_filter_conditions() {
+    let filter = "or=(a=equals:1,not=(b=equals:2))"
+        .parse::<Filter>()
+        .unwrap();
+    let keys: Vec<&String> = filter.conditions().into_iter().map(|(k, _)| k).collect();
+    assert_eq!(keys, vec!["a", "b"]);
+}
+
+#[test]
+fn test_parameters_group() {
+    let mut params = Parameters::new();
+    params
+        .equals("status".to_string(), vec!["active".to_string()])
+        .group(Filter::Or(vec![
+            condition("owner", Similarity::Equals, &["me"]),
+            condition("status", Similarity::Equals, &["draft"]),
+        ]));
+
+    assert_eq!(params.inner().len(), 1);
+    assert_eq!(params.groups().len(), 1);
+    assert_eq!(params.fields(), vec!["status", "owner"]);
+    assert_eq!(
+        format!("{}", params),
+        "status=equals:active&or=(owner=equals:me,status=equals:draft)"
+    );
+}
+
+#[test]
+fn test_parameters_from_str_with_groups() {
+    let params = "name=contains:jo&or=(a=equals:1,b=equals:2)&not=(c=equals:3)"
+        .parse::<Parameters>()
+        .unwrap();
+    assert_eq!(params.inner().len(), 1);
+    assert_eq!(params.groups().len(), 2);
+    assert_eq!(
+        params.groups()[1],
+        Filter::Not(Box::new(condition("c", Similarity::Equals, &["3"])))
+    );
+}
+
+#[test]
+fn test_parameters_keep_and_remove_prune_groups() {
+    let params = "name=contains:jo&or=(a=equals:1,b=equals:2)&not=(c=equals:3)"
+        .parse::<Parameters>()
+        .unwrap();
+
+    let kept = params.keep(vec!["name".to_string(), "a".to_string()]);
+    assert_eq!(kept.inner().len(), 1);
+    assert_eq!(
+        kept.groups(),
+        &vec![Filter::Or(vec![condition("a", Similarity::Equals, &["1"])])]
+    );
+
+    let removed = params.remove(vec!["a".to_string(), "c".to_string()]);
+    assert_eq!(removed.inner().len(), 1);
+    assert_eq!(
+        removed.groups(),
+        &vec![Filter::Or(vec![condition("b", Similarity::Equals, &["2"])])]
+    );
+}
+
+// ============================================================================
+// FIELDS TESTS
+// ============================================================================
+
+#[test]
+fn test_fields_builder() {
+    let mut fields = Fields::new();
+    assert!(fields.is_empty());
+
+    fields
+        .add("id".to_string())
+        .add("name".to_string())
+        .add("id".to_string());
+    assert_eq!(
+        fields.inner().iter().collect::<Vec<_>>(),
+        vec!["id", "name"]
+    );
+    assert_eq!(fields.to_string(), "id,name");
+}
+
+#[test]
+fn test_fields_from_str() {
+    let fields = " id , name,,a%2Cb,name ".parse::<Fields>().unwrap();
+    assert_eq!(
+        fields.inner().iter().collect::<Vec<_>>(),
+        vec!["id", "name", "a,b"]
+    );
+    assert_eq!(fields.to_string(), "id,name,a%2Cb");
+    assert_eq!(fields.to_string().parse::<Fields>().unwrap(), fields);
+    assert!("".parse::<Fields>().unwrap().is_empty());
+}
+
+#[test]
+fn test_fields_keep_and_remove() {
+    let fields = "id,name,email".parse::<Fields>().unwrap();
+
+    let kept = fields.keep(vec!["email".to_string(), "id".to_string()]);
+    assert_eq!(kept.to_string(), "id,email");
+
+    let removed = fields.remove(vec!["name".to_string()]);
+    assert_eq!(removed.to_string(), "id,email");
+}
+
+// ============================================================================
+// CURSOR TESTS
+// ============================================================================
+
+#[test]
+fn test_cursor_round_trip() {
+    let cursor = Cursor::new(vec![
+        "2025-01-01 10:00:00".to_string(),
+        "a,b&c=d".to_string(),
+        "测试".to_string(),
+        "".to_string(),
+        "42".to_string(),
+    ]);
+
+    let token = cursor.to_string();
+    assert!(
+        token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    );
+    assert_eq!(token.parse::<Cursor>().unwrap(), cursor);
+}
+
+#[test]
+fn test_cursor_token() {
+    // "v=1" in unpadded base64url
+    assert_eq!(Cursor::new(vec!["1".to_string()]).to_string(), "dj0x");
+    assert_eq!(
+        "dj0x".parse::<Cursor>().unwrap().values(),
+        &vec!["1".to_string()]
+    );
+    for len in 1..8 {
+        let cursor = Cursor::new(vec!["x".repeat(len)]);
+        assert_eq!(cursor.to_string().parse::<Cursor>().unwrap(), cursor);
+    }
+}
+
+#[test]
+fn test_cursor_invalid_tokens() {
+    for token in ["", "d", "dj0x!", "dj0x=", "a b"] {
+        assert_eq!(
+            token.parse::<Cursor>(),
+            Err(Error::InvalidCursor(token.to_string()))
+        );
+    }
+}
+
 // ============================================================================
 // QUERY TESTS
 // ============================================================================
@@ -1091,21 +1615,29 @@ fn test_query_init() {
     assert_eq!(query.offset, 10);
 }
 
-
 #[test]
 fn test_parameters_keep() {
     let mut params = Parameters::new();
     params.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["value1".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["value1".to_string()],
+        )],
     );
     params.inner_mut().insert(
         "surname".to_string(),
-        Parameter::init(Similarity::Equals, vec!["value2".to_string()]),
+        vec![Parameter::init(
+            Similarity::Equals,
+            vec!["value2".to_string()],
+        )],
     );
     params.inner_mut().insert(
         "email".to_string(),
-        Parameter::init(Similarity::StartsWith, vec!["value3".to_string()]),
+        vec![Parameter::init(
+            Similarity::StartsWith,
+            vec!["value3".to_string()],
+        )],
     );
 
     let filtered = params.keep(vec!["name".to_string(), "email".to_string()]);
@@ -1121,7 +1653,10 @@ fn test_parameters_keep_nonexistent_keys() {
     let mut params = Parameters::new();
     params.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["value1".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["value1".to_string()],
+        )],
     );
 
     let filtered = params.keep(vec!["nonexistent".to_string()]);
@@ -1133,7 +1668,10 @@ fn test_parameters_keep_empty_keys() {
     let mut params = Parameters::new();
     params.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["value1".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["value1".to_string()],
+        )],
     );
 
     let filtered = params.keep(vec![]);
@@ -1145,15 +1683,24 @@ fn test_parameters_remove() {
     let mut params = Parameters::new();
     params.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["value1".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["value1".to_string()],
+        )],
     );
     params.inner_mut().insert(
         "surname".to_string(),
-        Parameter::init(Similarity::Equals, vec!["value2".to_string()]),
+        vec![Parameter::init(
+            Similarity::Equals,
+            vec!["value2".to_string()],
+        )],
     );
     params.inner_mut().insert(
         "email".to_string(),
-        Parameter::init(Similarity::StartsWith, vec!["value3".to_string()]),
+        vec![Parameter::init(
+            Similarity::StartsWith,
+            vec!["value3".to_string()],
+        )],
     );
 
     let filtered = params.remove(vec!["name".to_string(), "email".to_string()]);
@@ -1169,7 +1716,10 @@ fn test_parameters_remove_nonexistent_keys() {
     let mut params = Parameters::new();
     params.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["value1".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["value1".to_string()],
+        )],
     );
 
     let filtered = params.remove(vec!["nonexistent".to_string()]);
@@ -1182,7 +1732,10 @@ fn test_parameters_remove_empty_keys() {
     let mut params = Parameters::new();
     params.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["value1".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["value1".to_string()],
+        )],
     );
 
     let filtered = params.remove(vec![]);
@@ -1190,25 +1743,30 @@ fn test_parameters_remove_empty_keys() {
     assert!(filtered.inner().contains_key("name"));
 }
 
-
 #[test]
 fn test_parameters_keep_with_numeric_comparisons() {
     let mut params = Parameters::new();
 
     params.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec!["20".to_string(), "30".to_string()],
-        ),
+        )],
     );
     params.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Greater, vec!["100".to_string()]),
+        vec![Parameter::init(
+            Similarity::Greater,
+            vec!["100".to_string()],
+        )],
     );
     params.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["damian".to_string()],
+        )],
     );
 
     let filtered = params.keep(vec!["age".to_string(), "name".to_string()]);
@@ -1218,7 +1776,7 @@ fn test_parameters_keep_with_numeric_comparisons() {
     assert!(!filtered.inner().contains_key("price"));
     assert!(filtered.inner().contains_key("name"));
 
-    let param = &filtered.inner()["age"];
+    let param = &filtered.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), vec!["20", "30"]);
 }
@@ -1229,18 +1787,24 @@ fn test_parameters_remove_with_numeric_comparisons() {
 
     params.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec!["20".to_string(), "30".to_string()],
-        ),
+        )],
     );
     params.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Greater, vec!["100".to_string()]),
+        vec![Parameter::init(
+            Similarity::Greater,
+            vec!["100".to_string()],
+        )],
     );
     params.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["damian".to_string()],
+        )],
     );
 
     let filtered = params.remove(vec!["age".to_string(), "name".to_string()]);
@@ -1250,7 +1814,7 @@ fn test_parameters_remove_with_numeric_comparisons() {
     assert!(filtered.inner().contains_key("price"));
     assert!(!filtered.inner().contains_key("name"));
 
-    let param = &filtered.inner()["price"];
+    let param = &filtered.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::Greater);
     assert_eq!(*param.values(), vec!["100"]);
 }
@@ -1286,7 +1850,6 @@ fn test_error_invalid_similarity() {
     }
 }
 
-
 #[test]
 fn test_complex_order_parsing() {
     let sort_str = "date_created:desc,name:asc,surname:asc,email:desc";
@@ -1315,19 +1878,19 @@ fn test_complex_parameters_parsing() {
 
     assert_eq!(params.inner().len(), 4);
 
-    let param = &params.inner()["name"];
+    let param = &params.inner()["name"][0];
     assert_eq!(*param.similarity(), Similarity::Contains);
     assert_eq!(*param.values(), vec!["damian"]);
 
-    let param = &params.inner()["surname"];
+    let param = &params.inner()["surname"][0];
     assert_eq!(*param.similarity(), Similarity::Equals);
     assert_eq!(*param.values(), vec!["black", "steel", "wood"]);
 
-    let param = &params.inner()["email"];
+    let param = &params.inner()["email"][0];
     assert_eq!(*param.similarity(), Similarity::StartsWith);
     assert_eq!(*param.values(), vec!["test"]);
 
-    let param = &params.inner()["age"];
+    let param = &params.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::EndsWith);
     assert_eq!(*param.values(), vec!["25"]);
 }
@@ -1349,23 +1912,23 @@ fn test_complex_numeric_parameters_parsing() {
 
     assert_eq!(params.inner().len(), 5);
 
-    let param = &params.inner()["age"];
+    let param = &params.inner()["age"][0];
     assert_eq!(*param.similarity(), Similarity::Between);
     assert_eq!(*param.values(), vec!["20", "30"]);
 
-    let param = &params.inner()["price"];
+    let param = &params.inner()["price"][0];
     assert_eq!(*param.similarity(), Similarity::Greater);
     assert_eq!(*param.values(), vec!["100"]);
 
-    let param = &params.inner()["score"];
+    let param = &params.inner()["score"][0];
     assert_eq!(*param.similarity(), Similarity::LesserOrEqual);
     assert_eq!(*param.values(), vec!["85"]);
 
-    let param = &params.inner()["rating"];
+    let param = &params.inner()["rating"][0];
     assert_eq!(*param.similarity(), Similarity::GreaterOrEqual);
     assert_eq!(*param.values(), vec!["4.5"]);
 
-    let param = &params.inner()["discount"];
+    let param = &params.inner()["discount"][0];
     assert_eq!(*param.similarity(), Similarity::Lesser);
     assert_eq!(*param.values(), vec!["10"]);
 }
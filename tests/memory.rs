@@ -0,0 +1,326 @@
+#![cfg(feature = "sql")]
+
+use query_lite::sql::{Filterable, Value};
+use query_lite::*;
+
+// ============================================================================
+// IN-MEMORY EVALUATION TESTS
+// ============================================================================
+
+#[derive(Clone, Debug, PartialEq)]
+struct Person {
+    id: i64,
+    name: &'static str,
+    age: Option<i64>,
+    email: Option<&'static str>,
+    score: f64,
+}
+
+impl Filterable for Person {
+    fn field(&self, name: &str) -> Option<Value> {
+        Some(match name {
+            "id" => Value::Integer(self.id),
+            "name" => Value::Text(self.name.to_string()),
+            "age" => self.age.map_or(Value::Null, Value::Integer),
+            "email" => self
+                .email
+                .map_or(Value::Null, |email| Value::Text(email.to_string())),
+            "score" => Value::Real(self.score),
+            _ => return None,
+        })
+    }
+}
+
+fn people() -> Vec<Person> {
+    vec![
+        Person {
+            id: 1,
+            name: "Ann",
+            age: Some(30),
+            email: Some("ann@example.com"),
+            score: 7.5,
+        },
+        Person {
+            id: 2,
+            name: "bob",
+            age: Some(17),
+            email: None,
+            score: 9.0,
+        },
+        Person {
+            id: 3,
+            name: "Cat",
+            age: Some(40),
+            email: Some("cat@test.org"),
+            score: 7.5,
+        },
+        Person {
+            id: 4,
+            name: "dan_1",
+            age: None,
+            email: Some("dan@example.com"),
+            score: 3.25,
+        },
+        Person {
+            id: 5,
+            name: "Eve",
+            age: Some(35),
+            email: Some("50%@example.com"),
+            score: 9.0,
+        },
+        Person {
+            id: 6,
+            name: "fay",
+            age: Some(15),
+            email: None,
+            score: 0.0,
+        },
+    ]
+}
+
+fn parsed(parameters: &str, order: &str) -> Query {
+    let mut query = Query::from(parameters.parse::<Parameters>().unwrap());
+    if !order.is_empty() {
+        query.order = order.parse().unwrap();
+    }
+    query
+}
+
+fn ids(query: &Query) -> Vec<i64> {
+    query
+        .apply(people())
+        .unwrap()
+        .iter()
+        .map(|person| person.id)
+        .collect()
+}
+
+#[test]
+fn test_memory_matches() {
+    let query = parsed("age=greater:18&name=contains:a", "");
+    let people = people();
+    assert!(query.matches(&people[0]).unwrap());
+    assert!(!query.matches(&people[1]).unwrap());
+    assert!(!query.matches(&people[4]).unwrap());
+}
+
+#[test]
+fn test_memory_comparisons() {
+    assert_eq!(ids(&parsed("age=greater-or-equal:30", "")), vec![1, 3, 5]);
+    assert_eq!(ids(&parsed("age=lesser:18", "")), vec![2, 6]);
+    assert_eq!(
+        ids(&parsed("age=between:15,17,35,40", "")),
+        vec![2, 3, 5, 6]
+    );
+    assert_eq!(ids(&parsed("age=not-between:16,35", "")), vec![3, 6]);
+    assert_eq!(ids(&parsed("score=equals:7.5", "")), vec![1, 3]);
+    assert_eq!(ids(&parsed("id=equals:2,4,6", "")), vec![2, 4, 6]);
+}
+
+#[test]
+fn test_memory_null_is_unknown() {
+    // NULL ages match neither a comparison nor its negation
+    assert_eq!(ids(&parsed("age=not-equals:30", "")), vec![2, 3, 5, 6]);
+    assert_eq!(ids(&parsed("age=not-between:0,100", "")), Vec::<i64>::new());
+    assert_eq!(ids(&parsed("email=not-contains:example", "")), vec![3]);
+
+    assert_eq!(ids(&parsed("age=is-null:", "")), vec![4]);
+    assert_eq!(ids(&parsed("email=is-not-null:", "")), vec![1, 3, 4, 5]);
+    assert_eq!(ids(&parsed("email=equals:null", "")), vec![2, 6]);
+    assert_eq!(ids(&parsed("age=not-equals:null,30", "")), vec![2, 3, 5, 6]);
+    assert_eq!(ids(&parsed("age=equals:null,17", "")), vec![2, 4]);
+}
+
+#[test]
+fn test_memory_like() {
+    // LIKE ignores ASCII case, as in SQLite
+    assert_eq!(ids(&parsed("name=starts-with:a", "")), vec![1]);
+    assert_eq!(ids(&parsed("name=ends-with:T", "")), vec![3]);
+    // Wildcards in values are escaped
+    assert_eq!(ids(&parsed("name=contains:_", "")), vec![4]);
+    assert_eq!(ids(&parsed("email=starts-with:50%", "")), vec![5]);
+    assert_eq!(ids(&parsed("email=contains:%", "")), vec![5]);
+    // `like` passes wildcards through
+    assert_eq!(ids(&parsed("name=like:_a_", "")), vec![3, 6]);
+    assert_eq!(ids(&parsed("email=like:%.org", "")), vec![3]);
+}
+
+#[test]
+fn test_memory_case_insensitive() {
+    assert_eq!(ids(&parsed("name=equals:ann", "")), Vec::<i64>::new());
+    assert_eq!(ids(&parsed("name=equals-ci:ann,BOB", "")), vec![1, 2]);
+    assert_eq!(
+        ids(&parsed("name=not-equals-ci:ANN", "")),
+        vec![2, 3, 4, 5, 6]
+    );
+}
+
+#[test]
+fn test_memory_groups() {
+    let query = parsed("or=(age=lesser:18,email=ends-with:.org)", "");
+    assert_eq!(ids(&query), vec![2, 3, 6]);
+
+    let query = parsed("not=(or=(age=lesser:18,email=ends-with:.org))", "");
+    assert_eq!(ids(&query), vec![1, 5]);
+}
+
+#[test]
+fn test_memory_sort_and_paginate() {
+    assert_eq!(ids(&parsed("", "age:asc")), vec![4, 6, 2, 1, 5, 3]);
+    assert_eq!(ids(&parsed("", "age:desc")), vec![3, 5, 1, 2, 6, 4]);
+    assert_eq!(
+        ids(&parsed("", "score:desc,id:desc")),
+        vec![5, 2, 3, 1, 4, 6]
+    );
+
+    let mut query = parsed("age=is-not-null:", "age:asc");
+    query.limit = 2;
+    query.offset = 1;
+    assert_eq!(ids(&query), vec![2, 1]);
+
+    let mut items = people();
+    query.renderer().sort(&mut items).unwrap();
+    assert_eq!(items[0].id, 4);
+    assert_eq!(query.renderer().paginate(items).len(), 2);
+}
+
+#[test]
+fn test_memory_cursor() {
+    let mut query = parsed("", "score:desc,id:asc");
+    query.cursor = Some(Cursor::new(vec!["9".to_string(), "2".to_string()]));
+    query.offset = 100;
+    assert_eq!(ids(&query), vec![5, 1, 3, 4, 6]);
+
+    query.cursor = Some(Cursor::new(vec!["9".to_string()]));
+    assert!(matches!(
+        query.apply(people()),
+        Err(error::Error::InvalidCursor(_))
+    ));
+    // The same error as rendering the query as SQL
+    assert_eq!(
+        query.apply(people()).map(|_| ()),
+        query.renderer().to_sql().map(|_| ())
+    );
+}
+
+#[test]
+fn test_memory_unknown_field() {
+    assert_eq!(
+        parsed("missing=equals:1", "").apply(people()),
+        Err(error::Error::UnknownField("missing".to_string()))
+    );
+    assert_eq!(
+        parsed("", "missing:asc").apply(people()),
+        Err(error::Error::UnknownField("missing".to_string()))
+    );
+}
+
+#[test]
+fn test_memory_schema() {
+    let mut schema = Schema::new();
+    schema
+        .field(Field::new("name".into()))
+        .field(Field::new("age".into()).kind(FieldType::Integer));
+
+    let query = parsed("age=greater:abc", "");
+    assert_eq!(
+        query.renderer().schema(&schema).apply(people()),
+        Err(error::Error::InvalidValue(
+            "age".to_string(),
+            "abc".to_string()
+        ))
+    );
+
+    let query = parsed("email=is-null:", "");
+    assert_eq!(
+        query.renderer().schema(&schema).apply(people()),
+        Err(error::Error::UnknownField("email".to_string()))
+    );
+}
+
+#[test]
+fn test_memory_maps() {
+    let item: std::collections::HashMap<String, Value> = [
+        ("name".to_string(), Value::Text("ann".to_string())),
+        ("age".to_string(), Value::Integer(30)),
+    ]
+    .into_iter()
+    .collect();
+    assert!(
+        parsed("age=greater:18&name=equals:ann", "")
+            .matches(&item)
+            .unwrap()
+    );
+    assert!(!parsed("age=lesser:18", "").matches(&item).unwrap());
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_memory_matches_sqlite() {
+    let connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE people (id INTEGER, name TEXT, age INTEGER, email TEXT, score REAL)",
+        )
+        .unwrap();
+    for person in people() {
+        connection
+            .execute(
+                "INSERT INTO people VALUES (?, ?, ?, ?, ?)",
+                rusqlite::params![
+                    person.id,
+                    person.name,
+                    person.age,
+                    person.email,
+                    person.score
+                ],
+            )
+            .unwrap();
+    }
+
+    let cases = [
+        ("", "id:asc"),
+        ("age=greater:18", "age:desc"),
+        ("age=not-equals:30", "id:asc"),
+        ("age=between:15,17,35,40", "id:desc"),
+        ("age=not-between:16,35", "id:asc"),
+        ("age=equals:null,17", "id:asc"),
+        ("age=not-equals:null,30", "id:asc"),
+        ("name=contains:A", "name:asc"),
+        ("name=contains:_", "id:asc"),
+        ("name=like:_a_", "id:asc"),
+        ("name=equals-ci:ann,BOB", "id:asc"),
+        ("name=starts-with-ci:D", "id:asc"),
+        ("email=not-ends-with:.com", "id:asc"),
+        ("email=starts-with:50%", "id:asc"),
+        ("email=is-null:", "id:asc"),
+        ("score=greater-or-equal:7.5", "score:desc,id:asc"),
+        ("score=equals:9", "id:asc"),
+        ("or=(age=lesser:18,email=ends-with:.org)", "id:asc"),
+        ("not=(or=(age=lesser:18,email=ends-with:.org))", "id:asc"),
+        ("", "age:asc,id:desc"),
+        ("", "email:desc,id:asc"),
+    ];
+
+    for (parameters, order) in cases {
+        let mut query = parsed(parameters, order);
+        for (limit, offset) in [(50, 0), (2, 1)] {
+            query.limit = limit;
+            query.offset = offset;
+            let sqlite = query
+                .fetch(&connection, "people", |row| row.get::<_, i64>("id"))
+                .unwrap();
+            assert_eq!(ids(&query), sqlite, "{} {}", parameters, order);
+        }
+    }
+
+    // Cursor pages agree with the SQL path
+    let mut query = Query::new();
+    query.order = "score:desc,id:asc".parse().unwrap();
+    query.limit = 2;
+    query.cursor = Some(Cursor::new(vec!["9".to_string(), "2".to_string()]));
+    let sqlite = query
+        .fetch(&connection, "people", |row| row.get::<_, i64>("id"))
+        .unwrap();
+    assert_eq!(ids(&query), sqlite);
+}
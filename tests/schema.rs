@@ -0,0 +1,719 @@
+use query_lite::error::Error;
+use query_lite::*;
+
+fn users_schema() -> Schema {
+    let mut schema = Schema::new();
+    schema
+        .field(Field::new("name".to_string()))
+        .field(Field::new("age".to_string()))
+        .field(Field::new("email".to_string()).sortable(false))
+        .field(Field::new("date_created".to_string()).filterable(false));
+    schema
+}
+
+// ============================================================================
+// SCHEMA TESTS
+// ============================================================================
+
+#[test]
+fn test_field_defaults() {
+    let field = Field::new("name".to_string());
+    assert_eq!(field.name(), "name");
+    assert!(field.is_filterable());
+    assert!(field.is_sortable());
+    assert!(field.is_selectable());
+    assert!(!field.selectable(false).is_selectable());
+}
+
+#[test]
+fn test_field_column() {
+    let field = Field::new("createdAt".to_string()).column("u.date_created".to_string());
+    assert_eq!(field.name(), "createdAt");
+    assert_eq!(field.column_name(), "u.date_created");
+    assert_eq!(Field::new("name".to_string()).column_name(), "name");
+}
+
+#[test]
+fn test_field_type() {
+    assert_eq!(Field::new("zip".to_string()).field_type(), None);
+    assert_eq!(
+        Field::new("zip".to_string())
+            .kind(FieldType::Text)
+            .field_type(),
+        Some(&FieldType::Text)
+    );
+}
+
+#[test]
+fn test_schema_lookup() {
+    let schema = users_schema();
+    assert_eq!(schema.inner().len(), 4);
+    assert!(schema.get("name").is_some());
+    assert!(schema.get("unknown").is_none());
+
+    assert!(schema.filter_field("email").is_ok());
+    assert_eq!(
+        schema.sort_field("email"),
+        Err(Error::UnknownField("email".to_string()))
+    );
+    assert!(schema.sort_field("date_created").is_ok());
+    assert_eq!(
+        schema.filter_field("date_created"),
+        Err(Error::UnknownField("date_created".to_string()))
+    );
+}
+
+#[test]
+fn test_schema_validate_accepts_known_fields() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .contains("name".to_string(), vec!["john".to_string()])
+        .greater("age".to_string(), vec!["18".to_string()]);
+    query.order.descending("date_created".to_string());
+
+    assert_eq!(users_schema().validate(&query), Ok(()));
+}
+
+#[test]
+fn test_schema_validate_rejects_unknown_parameter() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("1=1 OR name".to_string(), vec!["x".to_string()]);
+
+    assert_eq!(
+        users_schema().validate(&query),
+        Err(Error::UnknownField("1=1 OR name".to_string()))
+    );
+}
+
+#[test]
+fn test_schema_validate_checks_group_fields() {
+    let mut query = Query::new();
+    query.parameters.group(Filter::Or(vec![
+        Filter::Condition(
+            "name".to_string(),
+            Parameter::init(Similarity::Equals, vec!["x".to_string()]),
+        ),
+        Filter::Condition(
+            "password".to_string(),
+            Parameter::init(Similarity::Equals, vec!["x".to_string()]),
+        ),
+    ]));
+
+    assert_eq!(
+        users_schema().validate(&query),
+        Err(Error::UnknownField("password".to_string()))
+    );
+}
+
+#[test]
+fn test_schema_validate_rejects_unsortable_order() {
+    let mut query = Query::new();
+    query.order.ascending("email".to_string());
+
+    assert_eq!(
+        users_schema().validate(&query),
+        Err(Error::UnknownField("email".to_string()))
+    );
+}
+
+#[test]
+fn test_schema_validate_fields() {
+    let mut schema = users_schema();
+    schema.field(Field::new("password".to_string()).selectable(false));
+
+    let mut query = Query::new();
+    query
+        .fields
+        .add("name".to_string())
+        .add("email".to_string());
+    assert_eq!(schema.validate(&query), Ok(()));
+
+    query.fields.add("password".to_string());
+    assert_eq!(
+        schema.validate(&query),
+        Err(Error::UnknownField("password".to_string()))
+    );
+
+    let mut query = Query::new();
+    query.fields.add("unknown".to_string());
+    assert_eq!(
+        schema.validate_fields(&query.fields),
+        Err(Error::UnknownField("unknown".to_string()))
+    );
+}
+
+#[test]
+fn test_empty_schema_rejects_everything() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("name".to_string(), vec!["john".to_string()]);
+
+    assert!(Schema::new().validate(&query).is_err());
+    assert_eq!(Schema::new().validate(&Query::new()), Ok(()));
+}
+
+// ============================================================================
+// SQL RENDERING TESTS
+// ============================================================================
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_with_schema() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .contains("name".to_string(), vec!["john".to_string()]);
+    query.order.descending("date_created".to_string());
+
+    let schema = users_schema();
+    let renderer = query.renderer().schema(&schema);
+    assert_eq!(
+        renderer.to_sql().unwrap(),
+        "WHERE \"name\" LIKE ? ESCAPE '\\' ORDER BY \"date_created\" DESC LIMIT ? OFFSET ?"
+    );
+    assert_eq!(renderer.to_sql().unwrap(), query.to_sql());
+    assert_eq!(renderer.to_values().unwrap(), query.to_values());
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_rejects_unknown_parameter() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("1=1 OR name".to_string(), vec!["x".to_string()]);
+
+    let schema = users_schema();
+    let renderer = query.renderer().schema(&schema);
+    let expected = Err(Error::UnknownField("1=1 OR name".to_string()));
+    assert_eq!(renderer.to_sql(), expected);
+    assert_eq!(renderer.where_clause(), expected.map(Some));
+    assert!(renderer.parameter_values().is_err());
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_count_with_schema() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("password".to_string(), vec!["x".to_string()]);
+
+    let schema = users_schema();
+    let renderer = query.renderer().schema(&schema);
+    let expected = Err(Error::UnknownField("password".to_string()));
+    assert_eq!(renderer.to_count_sql("users"), expected);
+    assert_eq!(renderer.count_values(), expected.map(|_| vec![]));
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_rejects_unknown_order() {
+    let mut query = Query::new();
+    query.order.ascending("name; DROP TABLE users".to_string());
+
+    let schema = users_schema();
+    assert_eq!(
+        query.renderer().schema(&schema).order_clause(),
+        Err(Error::UnknownField("name; DROP TABLE users".to_string()))
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_maps_fields_to_columns() {
+    let mut schema = Schema::new();
+    schema
+        .field(Field::new("createdAt".to_string()).column("u.date_created".to_string()))
+        .field(Field::new("author.name".to_string()).column("a.display_name".to_string()));
+
+    let mut query = Query::new();
+    query
+        .parameters
+        .between(
+            "createdAt".to_string(),
+            vec!["2025-01-01".to_string(), "2025-02-01".to_string()],
+        )
+        .contains("author.name".to_string(), vec!["jo".to_string()]);
+    query
+        .order
+        .descending("createdAt".to_string())
+        .ascending("author.name".to_string());
+
+    let renderer = query.renderer().schema(&schema);
+    assert_eq!(
+        renderer.where_clause().unwrap(),
+        Some(
+            "\"u\".\"date_created\" BETWEEN ? AND ? AND \"a\".\"display_name\" LIKE ? ESCAPE '\\'"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        renderer.order_clause().unwrap(),
+        Some("\"u\".\"date_created\" DESC, \"a\".\"display_name\" ASC".to_string())
+    );
+    assert_eq!(
+        renderer.to_sql().unwrap(),
+        "WHERE \"u\".\"date_created\" BETWEEN ? AND ? AND \"a\".\"display_name\" LIKE ? ESCAPE '\\' ORDER BY \"u\".\"date_created\" DESC, \"a\".\"display_name\" ASC LIMIT ? OFFSET ?"
+    );
+    // Values are unaffected by the mapping
+    assert_eq!(
+        renderer.parameter_values().unwrap(),
+        query.parameter_values()
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_maps_field_to_expression() {
+    let mut schema = Schema::new();
+    schema.field(
+        Field::new("fullName".to_string())
+            .expression("(first_name || ' ' || last_name)".to_string()),
+    );
+
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("fullName".to_string(), vec!["Jo Black".to_string()]);
+
+    assert_eq!(
+        query.renderer().schema(&schema).to_sql().unwrap(),
+        "WHERE (first_name || ' ' || last_name) = ? LIMIT ? OFFSET ?"
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_maps_group_fields() {
+    let mut schema = Schema::new();
+    schema
+        .field(Field::new("status".to_string()).column("p.status".to_string()))
+        .field(Field::new("owner".to_string()).column("p.owner_id".to_string()));
+
+    let mut query = Query::new();
+    query.parameters.group(Filter::Or(vec![
+        Filter::Condition(
+            "status".to_string(),
+            Parameter::init(Similarity::Equals, vec!["draft".to_string()]),
+        ),
+        Filter::Condition(
+            "owner".to_string(),
+            Parameter::init(Similarity::Equals, vec!["7".to_string()]),
+        ),
+    ]));
+
+    assert_eq!(
+        query.renderer().schema(&schema).where_clause().unwrap(),
+        Some("\"p\".\"status\" = ? OR \"p\".\"owner_id\" = ?".to_string())
+    );
+}
+
+#[cfg(feature = "sql")]
+fn typed_schema() -> Schema {
+    let mut schema = Schema::new();
+    schema
+        .field(Field::new("zip".to_string()).kind(FieldType::Text))
+        .field(Field::new("age".to_string()).kind(FieldType::Integer))
+        .field(Field::new("score".to_string()).kind(FieldType::Real))
+        .field(Field::new("active".to_string()).kind(FieldType::Bool))
+        .field(Field::new("hash".to_string()).kind(FieldType::Blob))
+        .field(Field::new("note".to_string()));
+    schema
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_converts_declared_types() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("zip".to_string(), vec!["01234".to_string()])
+        .between("age".to_string(), vec!["18".to_string(), "65".to_string()])
+        .greater("score".to_string(), vec!["5".to_string()])
+        .equals(
+            "active".to_string(),
+            vec!["true".to_string(), "0".to_string()],
+        )
+        .equals("hash".to_string(), vec!["CAFE01".to_string()])
+        .equals("note".to_string(), vec!["01234".to_string()]);
+
+    let schema = typed_schema();
+    assert_eq!(
+        query.renderer().schema(&schema).parameter_values().unwrap(),
+        vec![
+            sql::Value::Text("01234".to_string()),
+            sql::Value::Integer(18),
+            sql::Value::Integer(65),
+            sql::Value::Real(5.0),
+            sql::Value::Integer(1),
+            sql::Value::Integer(0),
+            sql::Value::Blob(vec![0xca, 0xfe, 0x01]),
+            // Undeclared fields keep guessing the type
+            sql::Value::Integer(1234),
+        ]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_rejects_unconvertible_values() {
+    let schema = typed_schema();
+    let cases = [
+        ("age", "abc"),
+        ("age", "1.5"),
+        ("score", "high"),
+        ("active", "yes"),
+        ("hash", "xyz"),
+        ("hash", "abc"),
+    ];
+
+    for (key, value) in cases {
+        let mut query = Query::new();
+        query
+            .parameters
+            .equals(key.to_string(), vec![value.to_string()]);
+
+        let renderer = query.renderer().schema(&schema);
+        let expected = Err(Error::InvalidValue(key.to_string(), value.to_string()));
+        assert_eq!(renderer.parameter_values(), expected);
+        assert_eq!(renderer.to_values(), expected);
+    }
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_declared_types_skip_patterns_and_nulls() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .contains("age".to_string(), vec!["9".to_string()])
+        .equals("score".to_string(), vec![sql::NULL.to_string()]);
+    query
+        .parameters
+        .group(Filter::Not(Box::new(Filter::Condition(
+            "active".to_string(),
+            Parameter::init(Similarity::Equals, vec!["FALSE".to_string()]),
+        ))));
+
+    let schema = typed_schema();
+    assert_eq!(
+        query.renderer().schema(&schema).parameter_values().unwrap(),
+        vec![
+            sql::Value::Text("%9%".to_string()),
+            sql::Value::Null,
+            sql::Value::Integer(0),
+        ]
+    );
+}
+
+// 2025-03-15T12:30:45Z
+#[cfg(feature = "sql")]
+const NOW: i64 = 1_742_041_845;
+
+#[cfg(feature = "sql")]
+fn date_values(format: DateFormat, values: &[&str]) -> query_lite::error::Result<Vec<sql::Value>> {
+    let mut schema = Schema::new();
+    schema.field(Field::new("created".to_string()).kind(FieldType::Date(format)));
+
+    let mut query = Query::new();
+    query.parameters.equals(
+        "created".to_string(),
+        values.iter().map(|v| v.to_string()).collect(),
+    );
+    query.renderer().schema(&schema).now(NOW).parameter_values()
+}
+
+#[cfg(feature = "sql")]
+fn text(value: &str) -> sql::Value {
+    sql::Value::Text(value.to_string())
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_date_iso_forms() {
+    let values = date_values(
+        DateFormat::IsoDateTime,
+        &[
+            "2025-01-01",
+            "2025-01-01T10:30",
+            "2025-01-01 10:30:15",
+            "2025-01-01T10:30:15.250Z",
+            "2025-01-01T10:30:15+02:00",
+            "2025-01-01T23:30:00-0130",
+            "2024-02-29",
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(
+        values,
+        vec![
+            text("2025-01-01 00:00:00"),
+            text("2025-01-01 10:30:00"),
+            text("2025-01-01 10:30:15"),
+            text("2025-01-01 10:30:15"),
+            text("2025-01-01 08:30:15"),
+            text("2025-01-02 01:00:00"),
+            text("2024-02-29 00:00:00"),
+        ]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_date_relative_forms() {
+    let values = date_values(
+        DateFormat::IsoDateTime,
+        &[
+            "now",
+            "now-7d",
+            "today",
+            "today+1d",
+            "now-1h30m",
+            "now+2w-1s",
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(
+        values,
+        vec![
+            text("2025-03-15 12:30:45"),
+            text("2025-03-08 12:30:45"),
+            text("2025-03-15 00:00:00"),
+            text("2025-03-16 00:00:00"),
+            text("2025-03-15 11:00:45"),
+            text("2025-03-29 12:30:44"),
+        ]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_date_storage_formats() {
+    let values = ["1970-01-01", "2000-01-01T12:00:00Z", "now"];
+
+    assert_eq!(
+        date_values(DateFormat::IsoDate, &values).unwrap(),
+        vec![text("1970-01-01"), text("2000-01-01"), text("2025-03-15")]
+    );
+    assert_eq!(
+        date_values(DateFormat::UnixSeconds, &values).unwrap(),
+        vec![
+            sql::Value::Integer(0),
+            sql::Value::Integer(946_728_000),
+            sql::Value::Integer(NOW),
+        ]
+    );
+    // Matches SQLite's julianday()
+    assert_eq!(
+        date_values(DateFormat::JulianDay, &values[..2]).unwrap(),
+        vec![sql::Value::Real(2_440_587.5), sql::Value::Real(2_451_545.0)]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_date_rejects_invalid_values() {
+    let invalid = [
+        "2025-13-01",
+        "2025-02-29",
+        "2025-1-1",
+        "01/02/2025",
+        "2025-01-01T25:00",
+        "2025-01-01T10",
+        "2025-01-01X10:00",
+        // Non-ASCII offsets are rejected, not sliced mid-character
+        "2025-01-01T10:00+1é1",
+        "2025-01-01T10:00-éé",
+        "now-7",
+        "now-7y",
+        "now7d",
+        "nowhere",
+        "yesterday",
+    ];
+
+    for value in invalid {
+        assert_eq!(
+            date_values(DateFormat::IsoDate, &[value]),
+            Err(Error::InvalidValue(
+                "created".to_string(),
+                value.to_string()
+            )),
+            "{}",
+            value
+        );
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_date_between_with_sqlite() {
+    let mut schema = Schema::new();
+    schema.field(Field::new("created".to_string()).kind(FieldType::Date(DateFormat::UnixSeconds)));
+
+    let mut query = Query::new();
+    query.parameters.between(
+        "created".to_string(),
+        vec!["now-7d".to_string(), "now".to_string()],
+    );
+    let renderer = query.renderer().schema(&schema).now(NOW);
+
+    let connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(&format!(
+            "CREATE TABLE posts (id INTEGER, created INTEGER);
+             INSERT INTO posts VALUES (1, {}), (2, {}), (3, {});",
+            NOW - 8 * 86_400,
+            NOW - 86_400,
+            NOW,
+        ))
+        .unwrap();
+
+    let sql = format!(
+        "SELECT id FROM posts WHERE {}",
+        renderer.where_clause().unwrap().unwrap()
+    );
+    let ids: Vec<i64> = connection
+        .prepare(&sql)
+        .unwrap()
+        .query_map(
+            rusqlite::params_from_iter(renderer.parameter_values().unwrap()),
+            |row| row.get(0),
+        )
+        .unwrap()
+        .collect::<rusqlite::Result<_>>()
+        .unwrap();
+    assert_eq!(ids, vec![2, 3]);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_column_list_with_schema() {
+    let mut schema = Schema::new();
+    schema
+        .field(Field::new("id".to_string()))
+        .field(Field::new("email".to_string()).column("u.mail".to_string()))
+        .field(
+            Field::new("fullName".to_string())
+                .expression("first_name || ' ' || last_name".to_string()),
+        )
+        .field(Field::new("password".to_string()).selectable(false));
+
+    let mut query = Query::new();
+    query
+        .fields
+        .add("id".to_string())
+        .add("email".to_string())
+        .add("fullName".to_string());
+
+    let renderer = query.renderer().schema(&schema);
+    assert_eq!(
+        renderer.column_list().unwrap(),
+        Some(
+            "\"id\", \"u\".\"mail\" AS \"email\", first_name || ' ' || last_name AS \"fullName\""
+                .to_string()
+        )
+    );
+    assert_eq!(
+        renderer.select("users").to_sql().unwrap(),
+        "SELECT \"id\", \"u\".\"mail\" AS \"email\", first_name || ' ' || last_name AS \"fullName\" \
+         FROM \"users\" LIMIT ? OFFSET ?"
+    );
+
+    query.fields.add("password".to_string());
+    let renderer = query.renderer().schema(&schema);
+    assert_eq!(
+        renderer.column_list(),
+        Err(Error::UnknownField("password".to_string()))
+    );
+    assert_eq!(
+        renderer.select("users").to_sql(),
+        Err(Error::UnknownField("password".to_string()))
+    );
+}
+
+// ============================================================================
+// HTTP PARSING TESTS
+// ============================================================================
+
+#[cfg(feature = "http")]
+#[test]
+fn test_from_http_with_schema() {
+    let query = Query::from_http_with_schema(
+        "name=contains:john&age=greater:18&order=date_created:desc".to_string(),
+        &users_schema(),
+    )
+    .unwrap();
+    assert_eq!(query.parameters.inner().len(), 2);
+    assert_eq!(query.order.inner().len(), 1);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_from_http_with_schema_rejects_injection() {
+    let result = Query::from_http_with_schema(
+        "name--=equals:x&age=greater:18".to_string(),
+        &users_schema(),
+    );
+    assert_eq!(result, Err(Error::UnknownField("name--".to_string())));
+
+    let result =
+        Query::from_http_with_schema("name%20OR%201%3D1=equals:x".to_string(), &users_schema());
+    assert!(matches!(result, Err(Error::UnknownField(_))));
+}
+
+#[cfg(all(feature = "http", feature = "sql"))]
+#[test]
+fn test_keys_without_schema_are_quoted() {
+    let mut query = Query::from_http("name) OR (1=equals:x".to_string()).unwrap();
+    query.order.descending("a\"b".to_string());
+    assert_eq!(
+        query.to_sql(),
+        "WHERE \"name) OR (1\" = ? ORDER BY \"a\"\"b\" DESC LIMIT ? OFFSET ?"
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_from_http_with_schema_rejects_unknown_fields() {
+    let result = Query::from_http_with_schema(
+        "fields=name,(SELECT password FROM admins)".to_string(),
+        &users_schema(),
+    );
+    assert_eq!(
+        result,
+        Err(Error::UnknownField(
+            "(SELECT password FROM admins)".to_string()
+        ))
+    );
+}
+
+#[cfg(all(feature = "http", feature = "sql"))]
+#[test]
+fn test_from_http_date_times() {
+    let mut schema = Schema::new();
+    schema.field(Field::new("created".to_string()).kind(FieldType::Date(DateFormat::IsoDateTime)));
+
+    let query = Query::from_http_with_schema(
+        "created=between:2025-01-01T10:00:00,2025-01-01T18:30:00".to_string(),
+        &schema,
+    )
+    .unwrap();
+    assert_eq!(
+        query.renderer().schema(&schema).parameter_values().unwrap(),
+        vec![
+            sql::Value::Text("2025-01-01 10:00:00".to_string()),
+            sql::Value::Text("2025-01-01 18:30:00".to_string()),
+        ]
+    );
+
+    // Colons in values survive a round trip
+    let round_trip = Query::from_http(query.to_http()).unwrap();
+    assert_eq!(round_trip, query);
+}
@@ -0,0 +1,233 @@
+#![cfg(feature = "serde")]
+
+use query_lite::*;
+use serde_json::json;
+
+// ============================================================================
+// SERDE FEATURE TESTS
+// ============================================================================
+
+fn age_range() -> Vec<Parameter> {
+    vec![
+        Parameter::init(Similarity::Greater, vec!["18".to_string()]),
+        Parameter::init(Similarity::Lesser, vec!["65".to_string()]),
+    ]
+}
+
+fn sample() -> Query {
+    let mut query = Query::new();
+    query.parameters.inner_mut().insert(
+        "name".to_string(),
+        vec![
+            Parameter::init(Similarity::Contains, vec!["john".to_string()])
+                .with_case(Case::Insensitive),
+        ],
+    );
+    query
+        .parameters
+        .inner_mut()
+        .insert("age".to_string(), age_range());
+    query.parameters.groups_mut().push(Filter::Or(vec![
+        Filter::Condition(
+            "status".to_string(),
+            Parameter::init(Similarity::Equals, vec!["draft".to_string()]),
+        ),
+        Filter::Not(Box::new(Filter::Condition(
+            "owner".to_string(),
+            Parameter::init(Similarity::IsNull, vec![]),
+        ))),
+    ]));
+    query.order.descending("date_created".to_string());
+    query.order.ascending("id".to_string());
+    query.fields.add("id".to_string()).add("name".to_string());
+    query.limit = 25;
+    query.offset = 5;
+    query
+}
+
+#[test]
+fn test_serde_query_shape() {
+    assert_eq!(
+        serde_json::to_value(sample()).unwrap(),
+        json!({
+            "parameters": {
+                "conditions": {
+                    "name": [{ "similarity": "contains", "values": ["john"], "case": "insensitive" }],
+                    "age": [
+                        { "similarity": "greater", "values": ["18"] },
+                        { "similarity": "lesser", "values": ["65"] }
+                    ]
+                },
+                "groups": [
+                    { "or": [
+                        { "field": "status", "conditions": [{ "similarity": "equals", "values": ["draft"] }] },
+                        { "not": { "field": "owner", "conditions": [{ "similarity": "is-null", "values": [] }] } }
+                    ] }
+                ]
+            },
+            "order": [
+                { "field": "date_created", "direction": "desc" },
+                { "field": "id", "direction": "asc" }
+            ],
+            "fields": ["id", "name"],
+            "limit": 25,
+            "offset": 5,
+            "cursor": null
+        })
+    );
+}
+
+#[test]
+fn test_serde_query_round_trip() {
+    let query = sample();
+    let json = serde_json::to_string(&query).unwrap();
+    assert_eq!(serde_json::from_str::<Query>(&json).unwrap(), query);
+
+    let mut query = Query::new();
+    query
+        .order
+        .descending("score".to_string())
+        .ascending("id".to_string());
+    query.cursor = Some(Cursor::new(vec!["90".to_string(), "7".to_string()]));
+    let json = serde_json::to_value(&query).unwrap();
+    assert_eq!(json["cursor"], json!(["90", "7"]));
+    assert_eq!(
+        serde_json::from_value::<Query>(json.clone()).unwrap(),
+        query
+    );
+
+    // A cursor needs one value per order field
+    let mut json = json;
+    json["order"] = json!([{ "field": "score", "direction": "desc" }]);
+    assert!(serde_json::from_value::<Query>(json).is_err());
+}
+
+#[test]
+fn test_serde_query_defaults() {
+    assert_eq!(
+        serde_json::from_value::<Query>(json!({})).unwrap(),
+        Query::new()
+    );
+
+    let query: Query = serde_json::from_value(json!({
+        "parameters": { "conditions": { "age": [{ "similarity": "is-not-null" }] } },
+        "order": [{ "field": "age" }]
+    }))
+    .unwrap();
+    assert_eq!(
+        query.parameters.inner()["age"][0],
+        Parameter::init(Similarity::IsNotNull, vec![])
+    );
+    assert_eq!(query.order.inner()["age"], SortDirection::Ascending);
+    assert_eq!(query.limit, Parameters::DEFAULT_LIMIT);
+}
+
+#[test]
+fn test_serde_scalars() {
+    assert_eq!(
+        serde_json::to_value(Similarity::NotBetween).unwrap(),
+        json!("not-between")
+    );
+    assert_eq!(
+        serde_json::from_value::<Similarity>(json!("greater-or-equal")).unwrap(),
+        Similarity::GreaterOrEqual
+    );
+    assert_eq!(
+        serde_json::to_value(SortDirection::Descending).unwrap(),
+        json!("desc")
+    );
+    assert_eq!(
+        serde_json::from_value::<SortDirection>(json!("asc")).unwrap(),
+        SortDirection::Ascending
+    );
+    assert_eq!(
+        serde_json::to_value(Case::Insensitive).unwrap(),
+        json!("insensitive")
+    );
+}
+
+#[test]
+fn test_serde_parameter_and_order() {
+    assert_eq!(
+        serde_json::from_value::<Vec<Parameter>>(serde_json::to_value(age_range()).unwrap())
+            .unwrap(),
+        age_range()
+    );
+
+    // Several conditions on a field in a group are read as an `and` group
+    let filter: Filter = serde_json::from_value(json!({
+        "field": "age",
+        "conditions": serde_json::to_value(age_range()).unwrap()
+    }))
+    .unwrap();
+    assert_eq!(
+        filter,
+        Filter::And(
+            age_range()
+                .into_iter()
+                .map(|parameter| Filter::Condition("age".to_string(), parameter))
+                .collect()
+        )
+    );
+
+    let order: Order = serde_json::from_value(json!([
+        { "field": "b", "direction": "desc" },
+        { "field": "a", "direction": "asc" }
+    ]))
+    .unwrap();
+    assert_eq!(order.inner().keys().collect::<Vec<_>>(), vec!["b", "a"]);
+}
+
+#[test]
+fn test_serde_invalid() {
+    assert!(serde_json::from_value::<Similarity>(json!("sort-of")).is_err());
+    assert!(serde_json::from_value::<SortDirection>(json!("up")).is_err());
+    assert!(serde_json::from_value::<Case>(json!("upper")).is_err());
+    // A field needs at least one condition
+    assert!(serde_json::from_value::<Parameters>(json!({ "conditions": { "age": [] } })).is_err());
+    assert!(serde_json::from_value::<Filter>(json!({ "field": "age", "conditions": [] })).is_err());
+    // `is-null` takes neither values nor a case, as in `FromStr`
+    for condition in [
+        json!({ "similarity": "is-null", "values": ["x"] }),
+        json!({ "similarity": "is-null", "values": [], "case": "insensitive" }),
+    ] {
+        assert!(serde_json::from_value::<Parameter>(condition.clone()).is_err());
+        let second = json!([{ "similarity": "equals", "values": ["a"] }, condition]);
+        assert!(serde_json::from_value::<Vec<Parameter>>(second).is_err());
+    }
+    assert!(serde_json::from_value::<Query>(json!({ "limt": 10 })).is_err());
+    assert!(serde_json::from_value::<Parameters>(json!({ "groups": [{}] })).is_err());
+    assert!(
+        serde_json::from_value::<Parameters>(json!({
+            "groups": [{ "and": [], "or": [] }]
+        }))
+        .is_err()
+    );
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_serde_matches_from_http() {
+    let searches = [
+        "name=contains:damian&surname=equals:black,steel,wood&order=date_created:desc&limit=40&offset=0",
+        "age=greater:18&age=lesser:65&email=ends-with-ci:@EXAMPLE.COM",
+        "or=(age=lesser:18,not=(status=equals:active))&fields=id,name",
+        "deleted=is-null:&order=name:asc,id:desc&limit=5",
+    ];
+    for search in searches {
+        let query = Query::from_http(search.to_string()).unwrap();
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Query>(&json).unwrap(),
+            query,
+            "{}",
+            search
+        );
+    }
+
+    let mut query = Query::from_http("order=score:desc,id:asc&limit=10".to_string()).unwrap();
+    query.cursor = Some(Cursor::new(vec!["90".to_string(), "7".to_string()]));
+    let query = Query::from_http(query.to_http()).unwrap();
+    let json = serde_json::to_string(&query).unwrap();
+    assert_eq!(serde_json::from_str::<Query>(&json).unwrap(), query);
+}
@@ -18,11 +18,14 @@ fn test_query_to_sql_with_equals() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Equals, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::Equals,
+            vec!["damian".to_string()],
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE name = ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"name\" = ? LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -31,14 +34,14 @@ fn test_query_to_sql_with_equals_multiple_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec!["damian".to_string(), "john".to_string()],
-        ),
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE name IN (?, ?) LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"name\" IN (?, ?) LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -47,11 +50,14 @@ fn test_query_to_sql_with_equals_null() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Equals, vec![sql::NULL.to_string()]),
+        vec![Parameter::init(
+            Similarity::Equals,
+            vec![sql::NULL.to_string()],
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE name IS ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"name\" IS ? LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -60,11 +66,14 @@ fn test_query_to_sql_with_contains() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["damian".to_string()],
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE name LIKE ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"name\" LIKE ? ESCAPE '\\' LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -73,14 +82,17 @@ fn test_query_to_sql_with_contains_multiple_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Contains,
             vec!["damian".to_string(), "john".to_string()],
-        ),
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE (name LIKE ? OR name LIKE ?) LIMIT ? OFFSET ?");
+    assert_eq!(
+        sql,
+        "WHERE (\"name\" LIKE ? ESCAPE '\\' OR \"name\" LIKE ? ESCAPE '\\') LIMIT ? OFFSET ?"
+    );
 }
 
 #[cfg(feature = "sql")]
@@ -89,11 +101,14 @@ fn test_query_to_sql_with_starts_with() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::StartsWith, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::StartsWith,
+            vec!["damian".to_string()],
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE name LIKE ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"name\" LIKE ? ESCAPE '\\' LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -102,11 +117,14 @@ fn test_query_to_sql_with_ends_with() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::EndsWith, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::EndsWith,
+            vec!["damian".to_string()],
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE name LIKE ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"name\" LIKE ? ESCAPE '\\' LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -115,14 +133,14 @@ fn test_query_to_sql_with_between() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec!["20".to_string(), "30".to_string()],
-        ),
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE age BETWEEN ? AND ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"age\" BETWEEN ? AND ? LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -131,11 +149,11 @@ fn test_query_to_sql_with_lesser() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Lesser, vec!["100".to_string()]),
+        vec![Parameter::init(Similarity::Lesser, vec!["100".to_string()])],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE price < ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"price\" < ? LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -144,11 +162,14 @@ fn test_query_to_sql_with_lesser_or_equal() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::LesserOrEqual, vec!["100".to_string()]),
+        vec![Parameter::init(
+            Similarity::LesserOrEqual,
+            vec!["100".to_string()],
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE price <= ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"price\" <= ? LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -157,11 +178,11 @@ fn test_query_to_sql_with_greater() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Greater, vec!["50".to_string()]),
+        vec![Parameter::init(Similarity::Greater, vec!["50".to_string()])],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE price > ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"price\" > ? LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -170,11 +191,14 @@ fn test_query_to_sql_with_greater_or_equal() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::GreaterOrEqual, vec!["50".to_string()]),
+        vec![Parameter::init(
+            Similarity::GreaterOrEqual,
+            vec!["50".to_string()],
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE price >= ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"price\" >= ? LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -184,7 +208,7 @@ fn test_query_to_sql_with_order_by() {
     query.order.descending("date_created".to_string());
 
     let sql = query.to_sql();
-    assert_eq!(sql, "ORDER BY date_created DESC LIMIT ? OFFSET ?");
+    assert_eq!(sql, "ORDER BY \"date_created\" DESC LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -195,7 +219,10 @@ fn test_query_to_sql_with_multiple_order_by() {
     query.order.ascending("name".to_string());
 
     let sql = query.to_sql();
-    assert_eq!(sql, "ORDER BY date_created DESC, name ASC LIMIT ? OFFSET ?");
+    assert_eq!(
+        sql,
+        "ORDER BY \"date_created\" DESC, \"name\" ASC LIMIT ? OFFSET ?"
+    );
 }
 
 #[cfg(feature = "sql")]
@@ -206,18 +233,24 @@ fn test_query_to_sql_complex() {
     // Add multiple parameters
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["damian".to_string()],
+        )],
     );
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec!["20".to_string(), "30".to_string()],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Greater, vec!["100".to_string()]),
+        vec![Parameter::init(
+            Similarity::Greater,
+            vec!["100".to_string()],
+        )],
     );
 
     // Add sorting
@@ -226,7 +259,7 @@ fn test_query_to_sql_complex() {
     let sql = query.to_sql();
     assert_eq!(
         sql,
-        "WHERE name LIKE ? AND age BETWEEN ? AND ? AND price > ? ORDER BY date_created DESC LIMIT ? OFFSET ?"
+        "WHERE \"name\" LIKE ? ESCAPE '\\' AND \"age\" BETWEEN ? AND ? AND \"price\" > ? ORDER BY \"date_created\" DESC LIMIT ? OFFSET ?"
     );
 }
 
@@ -236,7 +269,7 @@ fn test_query_to_sql_with_empty_parameters() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec![]),
+        vec![Parameter::init(Similarity::Contains, vec![])],
     );
 
     let sql = query.to_sql();
@@ -259,14 +292,17 @@ fn test_query_to_sql_numeric_comparisons_multiple_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Greater,
             vec!["50".to_string(), "100".to_string()],
-        ),
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE (price > ? OR price > ?) LIMIT ? OFFSET ?");
+    assert_eq!(
+        sql,
+        "WHERE (\"price\" > ? OR \"price\" > ?) LIMIT ? OFFSET ?"
+    );
 }
 
 #[cfg(feature = "sql")]
@@ -275,7 +311,7 @@ fn test_query_to_sql_between_invalid_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(Similarity::Between, vec!["20".to_string()]),
+        vec![Parameter::init(Similarity::Between, vec!["20".to_string()])],
     );
 
     let sql = query.to_sql();
@@ -288,7 +324,7 @@ fn test_query_to_sql_between_multiple_pairs() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec![
                 "10".to_string(),
@@ -296,13 +332,13 @@ fn test_query_to_sql_between_multiple_pairs() {
                 "30".to_string(),
                 "40".to_string(),
             ],
-        ),
+        )],
     );
 
     let sql = query.to_sql();
     assert_eq!(
         sql,
-        "WHERE (age BETWEEN ? AND ? OR age BETWEEN ? AND ?) LIMIT ? OFFSET ?"
+        "WHERE (\"age\" BETWEEN ? AND ? OR \"age\" BETWEEN ? AND ?) LIMIT ? OFFSET ?"
     );
 }
 
@@ -312,7 +348,7 @@ fn test_query_to_sql_between_odd_values_ignored() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec![
                 "10".to_string(),
@@ -321,13 +357,13 @@ fn test_query_to_sql_between_odd_values_ignored() {
                 "40".to_string(),
                 "50".to_string(),
             ],
-        ),
+        )],
     );
 
     let sql = query.to_sql();
     assert_eq!(
         sql,
-        "WHERE (age BETWEEN ? AND ? OR age BETWEEN ? AND ?) LIMIT ? OFFSET ?"
+        "WHERE (\"age\" BETWEEN ? AND ? OR \"age\" BETWEEN ? AND ?) LIMIT ? OFFSET ?"
     );
 }
 
@@ -337,7 +373,7 @@ fn test_query_to_sql_between_three_pairs() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec![
                 "10".to_string(),
@@ -347,13 +383,13 @@ fn test_query_to_sql_between_three_pairs() {
                 "50".to_string(),
                 "60".to_string(),
             ],
-        ),
+        )],
     );
 
     let sql = query.to_sql();
     assert_eq!(
         sql,
-        "WHERE (age BETWEEN ? AND ? OR age BETWEEN ? AND ? OR age BETWEEN ? AND ?) LIMIT ? OFFSET ?"
+        "WHERE (\"age\" BETWEEN ? AND ? OR \"age\" BETWEEN ? AND ? OR \"age\" BETWEEN ? AND ?) LIMIT ? OFFSET ?"
     );
 }
 
@@ -363,14 +399,14 @@ fn test_query_to_sql_between_single_pair() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec!["20".to_string(), "30".to_string()],
-        ),
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE age BETWEEN ? AND ? LIMIT ? OFFSET ?");
+    assert_eq!(sql, "WHERE \"age\" BETWEEN ? AND ? LIMIT ? OFFSET ?");
 }
 
 #[cfg(feature = "sql")]
@@ -379,7 +415,7 @@ fn test_query_to_sql_between_empty_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(Similarity::Between, vec![]),
+        vec![Parameter::init(Similarity::Between, vec![])],
     );
 
     let sql = query.to_sql();
@@ -394,7 +430,7 @@ fn test_query_to_sql_between_complex_with_other_conditions() {
     // Add between with multiple pairs
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec![
                 "10".to_string(),
@@ -403,19 +439,22 @@ fn test_query_to_sql_between_complex_with_other_conditions() {
                 "40".to_string(),
                 "50".to_string(),
             ],
-        ),
+        )],
     );
 
     // Add other condition
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["damian".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["damian".to_string()],
+        )],
     );
 
     let sql = query.to_sql();
     assert_eq!(
         sql,
-        "WHERE (age BETWEEN ? AND ? OR age BETWEEN ? AND ?) AND name LIKE ? LIMIT ? OFFSET ?"
+        "WHERE (\"age\" BETWEEN ? AND ? OR \"age\" BETWEEN ? AND ?) AND \"name\" LIKE ? ESCAPE '\\' LIMIT ? OFFSET ?"
     );
 }
 
@@ -556,11 +595,11 @@ fn test_parameters_inner_method() {
     assert!(inner_map.contains_key("description"));
 
     // Test that we can access the underlying data
-    let name_param = inner_map.get("name").unwrap();
+    let name_param = &inner_map["name"][0];
     assert_eq!(*name_param.similarity(), Similarity::Equals);
     assert_eq!(*name_param.values(), vec!["test"]);
 
-    let desc_param = inner_map.get("description").unwrap();
+    let desc_param = &inner_map["description"][0];
     assert_eq!(*desc_param.similarity(), Similarity::Contains);
     assert_eq!(*desc_param.values(), vec!["rust"]);
 }
@@ -577,17 +616,17 @@ fn test_parameters_inner_mut_method() {
     // Test that we can modify the underlying data
     inner_map.insert(
         "new_key".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec!["10".to_string(), "20".to_string()],
-        ),
+        )],
     );
 
     // Verify the change
     assert_eq!(inner_map.len(), 2);
     assert!(inner_map.contains_key("new_key"));
 
-    let new_param = inner_map.get("new_key").unwrap();
+    let new_param = &inner_map["new_key"][0];
     assert_eq!(*new_param.similarity(), Similarity::Between);
     assert_eq!(*new_param.values(), vec!["10", "20"]);
 }
@@ -646,7 +685,7 @@ fn test_inner_methods_preserve_order() {
     let inner_map_mut = params.inner_mut();
     inner_map_mut.insert(
         "fourth".to_string(),
-        Parameter::init(Similarity::Greater, vec!["5".to_string()]),
+        vec![Parameter::init(Similarity::Greater, vec!["5".to_string()])],
     );
 
     let keys_after: Vec<&String> = inner_map_mut.keys().collect();
@@ -781,19 +820,18 @@ fn test_parameter_trait_with_parameters_collection() {
     params.contains("description".to_string(), vec!["rust".to_string()]);
 
     // Test accessing parameters using the trait methods
-    let name_param = params.inner().get("name").unwrap();
+    let name_param = &params.inner()["name"][0];
     assert_eq!(name_param.similarity(), &Similarity::Equals);
     assert_eq!(
         name_param.values(),
         &vec!["john".to_string(), "jane".to_string()]
     );
 
-    let desc_param = params.inner().get("description").unwrap();
+    let desc_param = &params.inner()["description"][0];
     assert_eq!(desc_param.similarity(), &Similarity::Contains);
     assert_eq!(desc_param.values(), &vec!["rust".to_string()]);
 }
 
-
 #[test]
 fn test_inner_methods_with_query_manipulation() {
     let mut query = Query::new();
@@ -823,7 +861,10 @@ fn test_inner_methods_with_query_manipulation() {
 
     param_map_mut.insert(
         "new_param".to_string(),
-        Parameter::init(Similarity::Greater, vec!["100".to_string()]),
+        vec![Parameter::init(
+            Similarity::Greater,
+            vec!["100".to_string()],
+        )],
     );
     sort_map_mut.insert("new_sort".to_string(), SortDirection::Ascending);
 
@@ -857,7 +898,7 @@ fn test_renamed_methods_with_query_building() {
     assert_eq!(query.offset, 10);
 
     // Verify parameter access using trait methods
-    let name_param = query.parameters.inner().get("name").unwrap();
+    let name_param = &query.parameters.inner()["name"][0];
     assert_eq!(name_param.similarity(), &Similarity::Equals);
     assert_eq!(name_param.values(), &vec!["john".to_string()]);
 
@@ -872,14 +913,13 @@ fn test_renamed_methods_with_query_building() {
     );
 }
 
-
 #[test]
 fn test_backward_compatibility_with_new_features() {
     // Test that old tuple access still works alongside new features
     let mut params = Parameters::new();
     params.equals("name".to_string(), vec!["john".to_string()]);
 
-    let param = params.inner().get("name").unwrap();
+    let param = &params.inner()["name"][0];
 
     // Old tuple access should still work
     assert_eq!(*param.similarity(), Similarity::Equals);
@@ -894,7 +934,6 @@ fn test_backward_compatibility_with_new_features() {
     assert_eq!(*param.values(), *param.values());
 }
 
-
 #[cfg(feature = "sql")]
 #[test]
 fn test_query_to_sql_normal_parameters() {
@@ -902,18 +941,21 @@ fn test_query_to_sql_normal_parameters() {
 
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec!["ben".to_string(), "john".to_string()],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(Similarity::Equals, vec!["20".to_string()]),
+        vec![Parameter::init(Similarity::Equals, vec!["20".to_string()])],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE name IN (?, ?) AND age = ? LIMIT ? OFFSET ?");
+    assert_eq!(
+        sql,
+        "WHERE \"name\" IN (?, ?) AND \"age\" = ? LIMIT ? OFFSET ?"
+    );
 }
 
 #[cfg(feature = "sql")]
@@ -923,18 +965,24 @@ fn test_query_to_sql_mixed_normal_and_similarity() {
 
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec!["ben".to_string(), "john".to_string()],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(Similarity::Contains, vec!["20".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["20".to_string()],
+        )],
     );
 
     let sql = query.to_sql();
-    assert_eq!(sql, "WHERE name IN (?, ?) AND age LIKE ? LIMIT ? OFFSET ?");
+    assert_eq!(
+        sql,
+        "WHERE \"name\" IN (?, ?) AND \"age\" LIKE ? ESCAPE '\\' LIMIT ? OFFSET ?"
+    );
 }
 
 // ============================================================================
@@ -973,7 +1021,7 @@ fn test_query_to_values_equals_integer() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(Similarity::Equals, vec!["25".to_string()]),
+        vec![Parameter::init(Similarity::Equals, vec!["25".to_string()])],
     );
 
     let values = query.to_values();
@@ -990,10 +1038,10 @@ fn test_query_to_values_equals_multiple_integers() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec!["25".to_string(), "30".to_string(), "35".to_string()],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1012,7 +1060,10 @@ fn test_query_to_values_equals_real() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Equals, vec!["25.5".to_string()]),
+        vec![Parameter::init(
+            Similarity::Equals,
+            vec!["25.5".to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1029,7 +1080,10 @@ fn test_query_to_values_equals_text() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Equals, vec!["john".to_string()]),
+        vec![Parameter::init(
+            Similarity::Equals,
+            vec!["john".to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1046,7 +1100,10 @@ fn test_query_to_values_equals_null() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "deleted_at".to_string(),
-        Parameter::init(Similarity::Equals, vec![sql::NULL.to_string()]),
+        vec![Parameter::init(
+            Similarity::Equals,
+            vec![sql::NULL.to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1063,7 +1120,10 @@ fn test_query_to_values_contains() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["john".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["john".to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1080,10 +1140,10 @@ fn test_query_to_values_contains_multiple() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Contains,
             vec!["john".to_string(), "jane".to_string()],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1101,7 +1161,10 @@ fn test_query_to_values_starts_with() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::StartsWith, vec!["john".to_string()]),
+        vec![Parameter::init(
+            Similarity::StartsWith,
+            vec!["john".to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1118,7 +1181,10 @@ fn test_query_to_values_ends_with() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::EndsWith, vec!["son".to_string()]),
+        vec![Parameter::init(
+            Similarity::EndsWith,
+            vec!["son".to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1135,10 +1201,10 @@ fn test_query_to_values_between() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Between,
             vec!["20".to_string(), "30".to_string()],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1156,7 +1222,7 @@ fn test_query_to_values_lesser() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Lesser, vec!["100".to_string()]),
+        vec![Parameter::init(Similarity::Lesser, vec!["100".to_string()])],
     );
 
     let values = query.to_values();
@@ -1173,7 +1239,10 @@ fn test_query_to_values_lesser_or_equal() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::LesserOrEqual, vec!["100".to_string()]),
+        vec![Parameter::init(
+            Similarity::LesserOrEqual,
+            vec!["100".to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1190,7 +1259,7 @@ fn test_query_to_values_greater() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Greater, vec!["50".to_string()]),
+        vec![Parameter::init(Similarity::Greater, vec!["50".to_string()])],
     );
 
     let values = query.to_values();
@@ -1207,7 +1276,10 @@ fn test_query_to_values_greater_or_equal() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::GreaterOrEqual, vec!["50".to_string()]),
+        vec![Parameter::init(
+            Similarity::GreaterOrEqual,
+            vec!["50".to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1224,19 +1296,28 @@ fn test_query_to_values_mixed_types() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["john".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["john".to_string()],
+        )],
     );
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(Similarity::Equals, vec!["25".to_string()]),
+        vec![Parameter::init(Similarity::Equals, vec!["25".to_string()])],
     );
     query.parameters.inner_mut().insert(
         "price".to_string(),
-        Parameter::init(Similarity::Greater, vec!["100.5".to_string()]),
+        vec![Parameter::init(
+            Similarity::Greater,
+            vec!["100.5".to_string()],
+        )],
     );
     query.parameters.inner_mut().insert(
         "deleted_at".to_string(),
-        Parameter::init(Similarity::Equals, vec![sql::NULL.to_string()]),
+        vec![Parameter::init(
+            Similarity::Equals,
+            vec![sql::NULL.to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1256,28 +1337,28 @@ fn test_query_to_values_complex_numeric_types() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "integers".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec!["123".to_string(), "-456".to_string(), "0".to_string()],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "reals".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec![
                 "123.45".to_string(),
                 "-456.78".to_string(),
                 "0.0".to_string(),
             ],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "mixed".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec!["123".to_string(), "123.45".to_string(), "text".to_string()],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1306,28 +1387,32 @@ fn test_query_to_values_edge_case_strings() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "special_chars".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Contains,
             vec!["hello%world".to_string(), "test_underscore".to_string()],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "unicode".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::StartsWith,
             vec!["测试".to_string(), "héllo".to_string()],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "empty_string".to_string(),
-        Parameter::init(Similarity::Equals, vec!["".to_string()]),
+        vec![Parameter::init(Similarity::Equals, vec!["".to_string()])],
     );
 
     let values = query.to_values();
 
     assert_eq!(values.len(), 6);
-    assert_eq!(values[0], sql::Value::Text("%hello%world%".to_string()));
-    assert_eq!(values[1], sql::Value::Text("%test_underscore%".to_string()));
+    // LIKE wildcards in the values are escaped
+    assert_eq!(values[0], sql::Value::Text("%hello\\%world%".to_string()));
+    assert_eq!(
+        values[1],
+        sql::Value::Text("%test\\_underscore%".to_string())
+    );
     assert_eq!(values[2], sql::Value::Text("测试%".to_string()));
     assert_eq!(values[3], sql::Value::Text("héllo%".to_string()));
     assert_eq!(values[4], sql::Value::Integer(50)); // limit
@@ -1340,7 +1425,7 @@ fn test_query_to_values_numeric_edge_cases() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "edge_numbers".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec![
                 "0".to_string(),
@@ -1352,7 +1437,7 @@ fn test_query_to_values_numeric_edge_cases() {
                 "inf".to_string(),
                 "nan".to_string(),
             ],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1380,23 +1465,23 @@ fn test_query_to_values_large_numbers() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "large_int".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec![
                 "9223372036854775807".to_string(),  // i64::MAX
                 "-9223372036854775808".to_string(), // i64::MIN
             ],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "large_real".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec![
                 "1.7976931348623157e308".to_string(),  // f64::MAX
                 "-1.7976931348623157e308".to_string(), // f64::MIN
             ],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1416,7 +1501,7 @@ fn test_query_to_values_null_variations() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "null_tests".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec![
                 sql::NULL.to_string(),
@@ -1424,7 +1509,7 @@ fn test_query_to_values_null_variations() {
                 "Null".to_string(),
                 "nUlL".to_string(),
             ],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1444,14 +1529,14 @@ fn test_query_to_values_whitespace_handling() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "whitespace".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec![
                 " 123 ".to_string(),
                 " 123.45 ".to_string(),
                 " hello ".to_string(),
             ],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1470,7 +1555,7 @@ fn test_query_to_values_empty_parameters() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "empty".to_string(),
-        Parameter::init(Similarity::Equals, vec![]),
+        vec![Parameter::init(Similarity::Equals, vec![])],
     );
 
     let values = query.to_values();
@@ -1487,11 +1572,17 @@ fn test_query_to_values_multiple_parameters_same_key() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["john".to_string()]),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["john".to_string()],
+        )],
     );
     query.parameters.inner_mut().insert(
         "name".to_string(), // This should overwrite the previous
-        Parameter::init(Similarity::StartsWith, vec!["jane".to_string()]),
+        vec![Parameter::init(
+            Similarity::StartsWith,
+            vec!["jane".to_string()],
+        )],
     );
 
     let values = query.to_values();
@@ -1504,201 +1595,1647 @@ fn test_query_to_values_multiple_parameters_same_key() {
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_query_to_values_parameter_order_preservation() {
+fn test_query_to_sql_multiple_conditions_same_key() {
     let mut query = Query::new();
-    query.parameters.inner_mut().insert(
-        "first".to_string(),
-        Parameter::init(Similarity::Equals, vec!["1".to_string()]),
-    );
-    query.parameters.inner_mut().insert(
-        "second".to_string(),
-        Parameter::init(Similarity::Contains, vec!["2".to_string()]),
+    query
+        .parameters
+        .greater("age".to_string(), vec!["18".to_string()])
+        .append(
+            "age".to_string(),
+            Parameter::init(Similarity::Lesser, vec!["65".to_string()]),
+        )
+        .equals("status".to_string(), vec!["active".to_string()]);
+
+    assert_eq!(
+        query.to_sql(),
+        "WHERE \"age\" > ? AND \"age\" < ? AND \"status\" = ? LIMIT ? OFFSET ?"
     );
-    query.parameters.inner_mut().insert(
-        "third".to_string(),
-        Parameter::init(Similarity::Between, vec!["3".to_string(), "4".to_string()]),
+    assert_eq!(
+        query.parameter_values(),
+        vec![
+            sql::Value::Integer(18),
+            sql::Value::Integer(65),
+            sql::Value::Text("active".to_string()),
+        ]
     );
-
-    let values = query.to_values();
-
-    assert_eq!(values.len(), 6);
-    assert_eq!(values[0], sql::Value::Integer(1));
-    assert_eq!(values[1], sql::Value::Text("%2%".to_string()));
-    assert_eq!(values[2], sql::Value::Integer(3));
-    assert_eq!(values[3], sql::Value::Integer(4));
-    assert_eq!(values[4], sql::Value::Integer(50)); // limit
-    assert_eq!(values[5], sql::Value::Integer(0)); // offset
+    assert_eq!(query.total_parameters(), 5);
 }
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_query_to_values_with_custom_limit_offset_complex() {
+fn test_query_to_sql_multiple_conditions_skips_empty() {
     let mut query = Query::new();
-    query.limit = 100;
-    query.offset = 25;
+    query
+        .parameters
+        .contains("name".to_string(), vec!["jo".to_string()])
+        .append(
+            "name".to_string(),
+            Parameter::init(Similarity::EndsWith, vec![]),
+        )
+        .append(
+            "name".to_string(),
+            Parameter::init(Similarity::StartsWith, vec!["j".to_string()]),
+        );
 
-    query.parameters.inner_mut().insert(
-        "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["john".to_string()]),
+    assert_eq!(
+        query.where_clause(),
+        Some("\"name\" LIKE ? ESCAPE '\\' AND \"name\" LIKE ? ESCAPE '\\'".to_string())
     );
-    query.parameters.inner_mut().insert(
-        "age".to_string(),
-        Parameter::init(
-            Similarity::Between,
-            vec!["20".to_string(), "30".to_string()],
-        ),
+    assert_eq!(
+        query.parameter_values(),
+        vec![
+            sql::Value::Text("%jo%".to_string()),
+            sql::Value::Text("j%".to_string()),
+        ]
     );
+}
 
-    let values = query.to_values();
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_with_not_equals() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .not_equals("status".to_string(), vec!["archived".to_string()]);
 
-    assert_eq!(values.len(), 5);
-    assert_eq!(values[0], sql::Value::Text("%john%".to_string()));
-    assert_eq!(values[1], sql::Value::Integer(20));
-    assert_eq!(values[2], sql::Value::Integer(30));
-    assert_eq!(values[3], sql::Value::Integer(100)); // custom limit
-    assert_eq!(values[4], sql::Value::Integer(25)); // custom offset
+    assert_eq!(query.to_sql(), "WHERE \"status\" <> ? LIMIT ? OFFSET ?");
+    assert_eq!(
+        query.parameter_values(),
+        vec![sql::Value::Text("archived".to_string())]
+    );
 }
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_sql_value_enum_variants() {
-    // Test all SQLValue enum variants
-    let null = sql::Value::Null;
-    let integer = sql::Value::Integer(42);
-    let real = sql::Value::Real(3.14);
-    let text = sql::Value::Text("hello".to_string());
-    let blob = sql::Value::Blob(vec![1, 2, 3, 4]);
+fn test_query_to_sql_with_not_equals_multiple_values() {
+    let mut query = Query::new();
+    query.parameters.not_equals(
+        "status".to_string(),
+        vec!["archived".to_string(), "deleted".to_string()],
+    );
 
-    assert_eq!(null, sql::Value::Null);
-    assert_eq!(integer, sql::Value::Integer(42));
-    assert_eq!(real, sql::Value::Real(3.14));
-    assert_eq!(text, sql::Value::Text("hello".to_string()));
-    assert_eq!(blob, sql::Value::Blob(vec![1, 2, 3, 4]));
+    assert_eq!(
+        query.to_sql(),
+        "WHERE \"status\" NOT IN (?, ?) LIMIT ? OFFSET ?"
+    );
 }
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_sql_value_enum_derived_traits() {
-    // Test Clone
-    let original = sql::Value::Text("test".to_string());
-    let cloned = original.clone();
-    assert_eq!(original, cloned);
+fn test_query_to_sql_with_not_equals_null() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .not_equals("deleted_at".to_string(), vec![sql::NULL.to_string()]);
 
-    // Test Debug
-    let debug_str = format!("{:?}", sql::Value::Integer(42));
-    assert!(debug_str.contains("Integer"));
-    assert!(debug_str.contains("42"));
+    assert_eq!(
+        query.to_sql(),
+        "WHERE \"deleted_at\" IS NOT ? LIMIT ? OFFSET ?"
+    );
+    assert_eq!(query.parameter_values(), vec![sql::Value::Null]);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_with_not_like_similarities() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .not_contains("name".to_string(), vec!["test".to_string()])
+        .not_starts_with("code".to_string(), vec!["tmp".to_string()])
+        .not_ends_with("email".to_string(), vec!["@spam.com".to_string()]);
 
-    // Test PartialEq
-    assert_eq!(sql::Value::Null, sql::Value::Null);
-    assert_eq!(sql::Value::Integer(42), sql::Value::Integer(42));
-    assert_eq!(sql::Value::Real(3.14), sql::Value::Real(3.14));
     assert_eq!(
-        sql::Value::Text("hello".to_string()),
-        sql::Value::Text("hello".to_string())
+        query.where_clause(),
+        Some("\"name\" NOT LIKE ? ESCAPE '\\' AND \"code\" NOT LIKE ? ESCAPE '\\' AND \"email\" NOT LIKE ? ESCAPE '\\'".to_string())
     );
     assert_eq!(
-        sql::Value::Blob(vec![1, 2, 3]),
-        sql::Value::Blob(vec![1, 2, 3])
+        query.parameter_values(),
+        vec![
+            sql::Value::Text("%test%".to_string()),
+            sql::Value::Text("tmp%".to_string()),
+            sql::Value::Text("%@spam.com".to_string()),
+        ]
     );
+}
 
-    // Test inequality
-    assert_ne!(sql::Value::Null, sql::Value::Integer(0));
-    assert_ne!(sql::Value::Integer(42), sql::Value::Integer(43));
-    assert_ne!(sql::Value::Real(3.14), sql::Value::Real(3.15));
-    assert_ne!(
-        sql::Value::Text("hello".to_string()),
-        sql::Value::Text("world".to_string())
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_with_not_contains_multiple_values() {
+    let mut query = Query::new();
+    query.parameters.not_contains(
+        "name".to_string(),
+        vec!["test".to_string(), "demo".to_string()],
     );
-    assert_ne!(
-        sql::Value::Blob(vec![1, 2, 3]),
-        sql::Value::Blob(vec![1, 2, 4])
+
+    // A negated match must exclude every value, so the comparisons are ANDed
+    assert_eq!(
+        query.where_clause(),
+        Some("(\"name\" NOT LIKE ? ESCAPE '\\' AND \"name\" NOT LIKE ? ESCAPE '\\')".to_string())
     );
 }
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_query_parameter_values() {
+fn test_query_to_sql_with_not_between() {
     let mut query = Query::new();
-    query.parameters.inner_mut().insert(
-        "name".to_string(),
-        Parameter::init(Similarity::Contains, vec!["john".to_string()]),
+    query
+        .parameters
+        .not_between("age".to_string(), vec!["20".to_string(), "30".to_string()]);
+    assert_eq!(
+        query.to_sql(),
+        "WHERE \"age\" NOT BETWEEN ? AND ? LIMIT ? OFFSET ?"
     );
-    query.parameters.inner_mut().insert(
+
+    query.parameters.not_between(
         "age".to_string(),
-        Parameter::init(Similarity::Equals, vec!["25".to_string()]),
+        vec![
+            "20".to_string(),
+            "30".to_string(),
+            "40".to_string(),
+            "50".to_string(),
+            "60".to_string(),
+        ],
+    );
+    assert_eq!(
+        query.where_clause(),
+        Some("(\"age\" NOT BETWEEN ? AND ? AND \"age\" NOT BETWEEN ? AND ?)".to_string())
+    );
+    assert_eq!(
+        query.parameter_values(),
+        vec![
+            sql::Value::Integer(20),
+            sql::Value::Integer(30),
+            sql::Value::Integer(40),
+            sql::Value::Integer(50),
+        ]
     );
-
-    let values = query.parameter_values();
-
-    assert_eq!(values.len(), 2);
-    assert_eq!(values[0], sql::Value::Text("%john%".to_string()));
-    assert_eq!(values[1], sql::Value::Integer(25));
 }
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_query_parameter_values_empty() {
-    let query = Query::new();
-    let values = query.parameter_values();
+fn test_query_to_sql_with_null_checks() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .is_null("deleted_at".to_string())
+        .is_not_null("email".to_string())
+        .equals("status".to_string(), vec!["active".to_string()]);
 
-    assert_eq!(values.len(), 0);
+    assert_eq!(
+        query.to_sql(),
+        "WHERE \"deleted_at\" IS NULL AND \"email\" IS NOT NULL AND \"status\" = ? LIMIT ? OFFSET ?"
+    );
+    // Null checks bind nothing
+    assert_eq!(
+        query.parameter_values(),
+        vec![sql::Value::Text("active".to_string())]
+    );
 }
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_query_pagination_values() {
+fn test_query_to_sql_with_null_check_in_group() {
     let mut query = Query::new();
-    query.limit = 100;
-    query.offset = 25;
-
-    let values = query.pagination_values();
+    query.parameters.group(Filter::Or(vec![
+        Filter::Condition(
+            "deleted_at".to_string(),
+            Parameter::init(Similarity::IsNull, vec![]),
+        ),
+        Filter::Condition(
+            "deleted_at".to_string(),
+            Parameter::init(Similarity::Greater, vec!["2025-01-01".to_string()]),
+        ),
+    ]));
 
-    assert_eq!(values.len(), 2);
-    assert_eq!(values[0], sql::Value::Integer(100));
-    assert_eq!(values[1], sql::Value::Integer(25));
+    assert_eq!(
+        query.where_clause(),
+        Some("\"deleted_at\" IS NULL OR \"deleted_at\" > ?".to_string())
+    );
+    assert_eq!(query.parameter_values().len(), 1);
 }
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_query_total_parameters() {
+fn test_query_to_sql_with_equals_null_among_values() {
     let mut query = Query::new();
-    query.parameters.inner_mut().insert(
+    query.parameters.equals(
         "name".to_string(),
-        Parameter::init(
-            Similarity::Contains,
-            vec!["john".to_string(), "jane".to_string()],
-        ),
-    );
-    query.parameters.inner_mut().insert(
-        "age".to_string(),
-        Parameter::init(Similarity::Equals, vec!["25".to_string()]),
+        vec![sql::NULL.to_string(), "foo".to_string()],
     );
 
-    let count = query.total_parameters();
+    // NULL never matches inside IN, so it is compared with IS
+    assert_eq!(
+        query.where_clause(),
+        Some("(\"name\" IS ? OR \"name\" = ?)".to_string())
+    );
+    assert_eq!(
+        query.parameter_values(),
+        vec![sql::Value::Null, sql::Value::Text("foo".to_string())]
+    );
 
-    // 2 name values + 1 age value + 2 pagination = 5 total
-    assert_eq!(count, 5);
+    query.parameters.equals(
+        "name".to_string(),
+        vec![
+            "foo".to_string(),
+            sql::NULL.to_string(),
+            "bar".to_string(),
+            sql::NULL.to_string(),
+        ],
+    );
+    assert_eq!(
+        query.where_clause(),
+        Some("(\"name\" IS ? OR \"name\" IN (?, ?))".to_string())
+    );
+    assert_eq!(
+        query.parameter_values(),
+        vec![
+            sql::Value::Null,
+            sql::Value::Text("foo".to_string()),
+            sql::Value::Text("bar".to_string()),
+        ]
+    );
 }
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_query_total_parameters_empty() {
-    let query = Query::new();
-    let count = query.total_parameters();
+fn test_query_to_sql_with_not_equals_null_among_values() {
+    let mut query = Query::new();
+    query.parameters.not_equals(
+        "name".to_string(),
+        vec![sql::NULL.to_string(), "foo".to_string(), "bar".to_string()],
+    );
 
-    // Only pagination values
-    assert_eq!(count, 2);
+    assert_eq!(
+        query.where_clause(),
+        Some("(\"name\" IS NOT ? AND \"name\" NOT IN (?, ?))".to_string())
+    );
+    assert_eq!(query.parameter_values()[0], sql::Value::Null);
 }
 
 #[cfg(feature = "sql")]
 #[test]
-fn test_query_total_parameters_with_empty_parameters() {
+fn test_renderer_without_null_sentinel() {
     let mut query = Query::new();
-    query.parameters.inner_mut().insert(
-        "empty".to_string(),
-        Parameter::init(Similarity::Equals, vec![]),
-    );
+    query
+        .parameters
+        .equals(
+            "name".to_string(),
+            vec![sql::NULL.to_string(), "foo".to_string()],
+        )
+        .contains("nickname".to_string(), vec![sql::NULL.to_string()])
+        .is_null("deleted_at".to_string());
+
+    let renderer = query.renderer().null_sentinel(false);
+    assert_eq!(
+        renderer.where_clause().unwrap(),
+        Some(
+            "\"name\" IN (?, ?) AND \"nickname\" LIKE ? ESCAPE '\\' AND \"deleted_at\" IS NULL"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        renderer.parameter_values().unwrap(),
+        vec![
+            sql::Value::Text("null".to_string()),
+            sql::Value::Text("foo".to_string()),
+            sql::Value::Text("%null%".to_string()),
+        ]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_escape_like() {
+    assert_eq!(sql::escape_like("50%"), "50\\%");
+    assert_eq!(sql::escape_like("user_1"), "user\\_1");
+    assert_eq!(sql::escape_like("C:\\temp"), "C:\\\\temp");
+    assert_eq!(sql::escape_like("%"), "\\%");
+    assert_eq!(sql::escape_like("plain"), "plain");
+    assert_eq!(sql::LIKE_ESCAPE, '\\');
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_values_escapes_like_wildcards() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .contains("discount".to_string(), vec!["50%".to_string()])
+        .starts_with("login".to_string(), vec!["user_1".to_string()])
+        .not_ends_with("path".to_string(), vec!["\\tmp".to_string()]);
+
+    assert_eq!(
+        query.where_clause(),
+        Some(
+            "\"discount\" LIKE ? ESCAPE '\\' AND \"login\" LIKE ? ESCAPE '\\' AND \"path\" NOT LIKE ? ESCAPE '\\'"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        query.parameter_values(),
+        vec![
+            sql::Value::Text("%50\\%%".to_string()),
+            sql::Value::Text("user\\_1%".to_string()),
+            sql::Value::Text("%\\\\tmp".to_string()),
+        ]
+    );
+
+    // A lone wildcard no longer matches everything
+    query
+        .parameters
+        .contains("discount".to_string(), vec!["%".to_string()]);
+    assert_eq!(
+        query.parameter_values()[0],
+        sql::Value::Text("%\\%%".to_string())
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_with_raw_like() {
+    let mut query = Query::new();
+    query.parameters.like(
+        "code".to_string(),
+        vec!["A_B%".to_string(), "%-x".to_string()],
+    );
+
+    // Raw patterns keep their wildcards and need no ESCAPE clause
+    assert_eq!(
+        query.to_sql(),
+        "WHERE (\"code\" LIKE ? OR \"code\" LIKE ?) LIMIT ? OFFSET ?"
+    );
+    assert_eq!(
+        query.parameter_values(),
+        vec![
+            sql::Value::Text("A_B%".to_string()),
+            sql::Value::Text("%-x".to_string()),
+        ]
+    );
+}
+
+#[cfg(feature = "sql")]
+fn case_insensitive(similarity: Similarity, values: &[&str]) -> Parameter {
+    Parameter::init(similarity, values.iter().map(|v| v.to_string()).collect())
+        .with_case(Case::Insensitive)
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_case_insensitive_lower() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .append(
+            "name".to_string(),
+            case_insensitive(Similarity::Equals, &["John"]),
+        )
+        .append(
+            "role".to_string(),
+            case_insensitive(Similarity::NotEquals, &["Admin", "Root"]),
+        )
+        .append(
+            "email".to_string(),
+            case_insensitive(Similarity::Contains, &["Gmail"]),
+        )
+        .append(
+            "code".to_string(),
+            case_insensitive(Similarity::Like, &["A_%"]),
+        )
+        .append(
+            "city".to_string(),
+            case_insensitive(Similarity::Between, &["a", "m"]),
+        );
+
+    assert_eq!(
+        query.where_clause(),
+        Some(
+            "lower(\"name\") = lower(?) AND lower(\"role\") NOT IN (lower(?), lower(?)) AND lower(\"email\") LIKE lower(?) ESCAPE '\\' AND lower(\"code\") LIKE lower(?) AND lower(\"city\") BETWEEN lower(?) AND lower(?)"
+                .to_string()
+        )
+    );
+    // Values are bound unchanged; the SQL does the folding
+    assert_eq!(
+        query.parameter_values()[0],
+        sql::Value::Text("John".to_string())
+    );
+    assert_eq!(query.parameter_values().len(), 7);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_case_insensitive_null_sentinel() {
+    let mut query = Query::new();
+    query.parameters.append(
+        "name".to_string(),
+        case_insensitive(Similarity::Equals, &[sql::NULL, "John"]),
+    );
+
+    assert_eq!(
+        query.where_clause(),
+        Some("(\"name\" IS ? OR lower(\"name\") = lower(?))".to_string())
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_fold_collate() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .append(
+            "name".to_string(),
+            case_insensitive(Similarity::Equals, &["John"]),
+        )
+        .append(
+            "role".to_string(),
+            case_insensitive(Similarity::Equals, &["admin", "root"]),
+        )
+        .append(
+            "email".to_string(),
+            case_insensitive(Similarity::StartsWith, &["Jo"]),
+        )
+        .equals("status".to_string(), vec!["Active".to_string()]);
+
+    let renderer = query.renderer().fold(sql::Fold::Collate("NOCASE"));
+    assert_eq!(
+        renderer.where_clause().unwrap(),
+        Some(
+            "\"name\" COLLATE NOCASE = ? AND \"role\" COLLATE NOCASE IN (?, ?) AND lower(\"email\") LIKE lower(?) ESCAPE '\\' AND \"status\" = ?"
+                .to_string()
+        )
+    );
+
+    let renderer = query
+        .renderer()
+        .fold(sql::Fold::Function(sql::UNICODE_LOWER));
+    assert_eq!(
+        renderer.where_clause().unwrap(),
+        Some(
+            "unicode_lower(\"name\") = unicode_lower(?) AND unicode_lower(\"role\") IN (unicode_lower(?), unicode_lower(?)) AND unicode_lower(\"email\") LIKE unicode_lower(?) ESCAPE '\\' AND \"status\" = ?"
+                .to_string()
+        )
+    );
+}
+
+#[cfg(feature = "rusqlite")]
+fn matching_names(renderer: sql::Renderer) -> Vec<String> {
+    let connection = rusqlite::Connection::open_in_memory().unwrap();
+    sql::register_unicode(&connection).unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE users (name TEXT);
+             INSERT INTO users VALUES ('Émile'), ('émile'), ('EMILE'), ('John');",
+        )
+        .unwrap();
+
+    let sql = format!(
+        "SELECT name FROM users WHERE {} ORDER BY rowid",
+        renderer.where_clause().unwrap().unwrap()
+    );
+    let mut statement = connection.prepare(&sql).unwrap();
+    statement
+        .query_map(
+            rusqlite::params_from_iter(renderer.parameter_values().unwrap()),
+            |row| row.get(0),
+        )
+        .unwrap()
+        .collect::<rusqlite::Result<Vec<String>>>()
+        .unwrap()
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_case_insensitive_unicode_matching() {
+    let mut query = Query::new();
+    query.parameters.append(
+        "name".to_string(),
+        case_insensitive(Similarity::Equals, &["ÉMILE"]),
+    );
+
+    // SQLite's own lower() leaves the accented capital alone
+    assert_eq!(matching_names(query.renderer()), vec!["Émile"]);
+
+    let unicode = vec!["Émile".to_string(), "émile".to_string()];
+    let renderer = query
+        .renderer()
+        .fold(sql::Fold::Function(sql::UNICODE_LOWER));
+    assert_eq!(matching_names(renderer), unicode);
+    let renderer = query
+        .renderer()
+        .fold(sql::Fold::Collate(sql::UNICODE_NOCASE));
+    assert_eq!(matching_names(renderer), unicode);
+
+    let mut query = Query::new();
+    query.parameters.append(
+        "name".to_string(),
+        case_insensitive(Similarity::Contains, &["MIL"]),
+    );
+    let renderer = query
+        .renderer()
+        .fold(sql::Fold::Function(sql::UNICODE_LOWER));
+    assert_eq!(matching_names(renderer), vec!["Émile", "émile", "EMILE"]);
+}
+
+#[cfg(feature = "sql")]
+fn cursor(values: &[&str]) -> Option<Cursor> {
+    Some(Cursor::new(values.iter().map(|v| v.to_string()).collect()))
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_with_cursor_single_key() {
+    let mut query = Query::new();
+    query.order.ascending("id".to_string());
+    query.limit = 20;
+    query.cursor = cursor(&["100"]);
+
+    assert_eq!(
+        query.to_sql(),
+        "WHERE \"id\" > ? ORDER BY \"id\" ASC LIMIT ?"
+    );
+    assert_eq!(
+        query.to_values(),
+        vec![sql::Value::Integer(100), sql::Value::Integer(20)]
+    );
+    assert_eq!(query.pagination_values(), vec![sql::Value::Integer(20)]);
+
+    query.order = Order::new();
+    query.order.descending("id".to_string());
+    assert_eq!(query.where_clause(), Some("\"id\" < ?".to_string()));
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_with_cursor_row_values() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("status".to_string(), vec!["active".to_string()]);
+    query
+        .order
+        .descending("created".to_string())
+        .descending("id".to_string());
+    query.cursor = cursor(&["2025-01-01", "7"]);
+
+    assert_eq!(
+        query.to_sql(),
+        "WHERE \"status\" = ? AND (\"created\", \"id\") < (?, ?) ORDER BY \"created\" DESC, \"id\" DESC LIMIT ?"
+    );
+    assert_eq!(
+        query.to_values(),
+        vec![
+            sql::Value::Text("active".to_string()),
+            sql::Value::Text("2025-01-01".to_string()),
+            sql::Value::Integer(7),
+            sql::Value::Integer(50),
+        ]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_with_cursor_mixed_directions() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("status".to_string(), vec!["active".to_string()]);
+    query
+        .order
+        .ascending("name".to_string())
+        .descending("created".to_string())
+        .ascending("id".to_string());
+    query.cursor = cursor(&["bob", "2025-01-01", "7"]);
+
+    assert_eq!(
+        query.where_clause(),
+        Some(
+            "\"status\" = ? AND (\"name\" > ? OR (\"name\" = ? AND \"created\" < ?) OR (\"name\" = ? AND \"created\" = ? AND \"id\" > ?))"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        query.parameter_values(),
+        vec![
+            sql::Value::Text("active".to_string()),
+            sql::Value::Text("bob".to_string()),
+            sql::Value::Text("bob".to_string()),
+            sql::Value::Text("2025-01-01".to_string()),
+            sql::Value::Text("bob".to_string()),
+            sql::Value::Text("2025-01-01".to_string()),
+            sql::Value::Integer(7),
+        ]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_renderer_rejects_cursor_not_matching_order() {
+    let mut query = Query::new();
+    query.cursor = cursor(&["7"]);
+
+    // A cursor needs an order to seek along
+    let expected = Err(query_lite::error::Error::InvalidCursor(
+        query.cursor.as_ref().unwrap().to_string(),
+    ));
+    assert_eq!(query.renderer().to_sql(), expected);
+
+    query
+        .order
+        .ascending("created".to_string())
+        .ascending("id".to_string());
+    assert_eq!(query.renderer().where_clause(), expected.map(Some));
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_to_sql_pages_by_offset_on_cursor_not_matching_order() {
+    // Never an empty tail that would drop the filters and the LIMIT
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("tenant".to_string(), vec!["7".to_string()]);
+    query.cursor = cursor(&["7"]);
+    assert_eq!(query.to_sql(), "WHERE \"tenant\" = ? LIMIT ? OFFSET ?");
+    assert_eq!(
+        query.to_values(),
+        vec![
+            sql::Value::Integer(7),
+            sql::Value::Integer(Parameters::DEFAULT_LIMIT as i64),
+            sql::Value::Integer(Parameters::DEFAULT_OFFSET as i64),
+        ]
+    );
+    assert_eq!(query.pagination_values().len(), 2);
+    assert!(query.renderer().to_sql().is_err());
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_cursor_pagination_with_sqlite() {
+    let connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE posts (id INTEGER, score INTEGER);
+             INSERT INTO posts VALUES (1, 5), (2, 3), (3, 5), (4, 1), (5, 3), (6, 5), (7, 2);",
+        )
+        .unwrap();
+
+    let mut query = Query::new();
+    query
+        .order
+        .descending("score".to_string())
+        .ascending("id".to_string());
+    query.limit = 3;
+
+    let mut seen = Vec::new();
+    loop {
+        let sql = format!("SELECT id, score FROM posts {}", query.to_sql());
+        let rows: Vec<(i64, i64)> = connection
+            .prepare(&sql)
+            .unwrap()
+            .query_map(rusqlite::params_from_iter(query.to_values()), |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+
+        seen.extend(rows.iter().map(|(id, _)| *id));
+        match rows.last() {
+            Some((id, score)) if rows.len() == query.limit => {
+                query.cursor = cursor(&[&score.to_string(), &id.to_string()]);
+            }
+            _ => break,
+        }
+    }
+
+    assert_eq!(seen, vec![1, 3, 6, 2, 5, 7, 4]);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_count_sql() {
+    let mut query = Query::new();
+    assert_eq!(
+        query.to_count_sql("users"),
+        "SELECT COUNT(*) FROM \"users\""
+    );
+    assert!(query.count_values().is_empty());
+    // The table is quoted like in `select()`, `update()` and `delete()`
+    assert_eq!(
+        query.to_count_sql("users; DROP TABLE users"),
+        "SELECT COUNT(*) FROM \"users; DROP TABLE users\""
+    );
+
+    query
+        .parameters
+        .contains("name".to_string(), vec!["jo".to_string()])
+        .between("age".to_string(), vec!["18".to_string(), "65".to_string()]);
+    query.order.descending("age".to_string());
+    query.limit = 10;
+    query.offset = 20;
+
+    assert_eq!(
+        query.to_count_sql("users"),
+        "SELECT COUNT(*) FROM \"users\" WHERE \"name\" LIKE ? ESCAPE '\\' AND \"age\" BETWEEN ? AND ?"
+    );
+    // Same values as the page, minus limit and offset
+    assert_eq!(query.count_values(), query.parameter_values());
+    assert_eq!(query.count_values().len(), 3);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_count_sql_ignores_cursor() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("status".to_string(), vec!["active".to_string()])
+        .greater("age".to_string(), vec!["18".to_string()]);
+    query.order.ascending("id".to_string());
+    query.cursor = Some(Cursor::new(vec!["100".to_string()]));
+
+    assert_eq!(
+        query.where_clause(),
+        Some("\"status\" = ? AND \"age\" > ? AND \"id\" > ?".to_string())
+    );
+    assert_eq!(
+        query.to_count_sql("users"),
+        "SELECT COUNT(*) FROM \"users\" WHERE \"status\" = ? AND \"age\" > ?"
+    );
+    assert_eq!(
+        query.count_values(),
+        vec![
+            sql::Value::Text("active".to_string()),
+            sql::Value::Integer(18),
+        ]
+    );
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_query_count_with_sqlite() {
+    let connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE users (name TEXT, age INTEGER);
+             INSERT INTO users VALUES ('john', 30), ('joan', 17), ('jo', 40), ('bob', 50);",
+        )
+        .unwrap();
+
+    let mut query = Query::new();
+    query
+        .parameters
+        .starts_with("name".to_string(), vec!["jo".to_string()])
+        .greater_or_equal("age".to_string(), vec!["18".to_string()]);
+    query.limit = 1;
+
+    let total: i64 = connection
+        .query_row(
+            &query.to_count_sql("users"),
+            rusqlite::params_from_iter(query.count_values()),
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(total, 2);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_quote_identifier() {
+    assert_eq!(sql::quote_identifier("users"), "\"users\"");
+    assert_eq!(sql::quote_identifier("u.name"), "\"u\".\"name\"");
+    assert_eq!(sql::quote_identifier("u.*"), "\"u\".*");
+    assert_eq!(sql::quote_identifier("odd\"name"), "\"odd\"\"name\"");
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_select_all() {
+    let query = Query::new();
+    let select = query.select("users");
+    assert_eq!(
+        select.to_sql().unwrap(),
+        "SELECT * FROM \"users\" LIMIT ? OFFSET ?"
+    );
+    assert_eq!(select.to_values().unwrap(), query.to_values());
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_select_with_columns_and_joins() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("p.status".to_string(), vec!["published".to_string()]);
+    query.order.descending("p.created".to_string());
+    query.limit = 10;
+
+    let select = query
+        .select("users")
+        .alias("u")
+        .columns(&["u.id", "u.name"])
+        .column("p.title")
+        .join(sql::Join::left("posts", "p.user_id = u.id").alias("p"))
+        .join(sql::Join::inner("teams", "teams.id = u.team_id"));
+
+    assert_eq!(
+        select.to_sql().unwrap(),
+        "SELECT \"u\".\"id\", \"u\".\"name\", \"p\".\"title\" FROM \"users\" AS \"u\" \
+         LEFT JOIN \"posts\" AS \"p\" ON p.user_id = u.id \
+         INNER JOIN \"teams\" ON teams.id = u.team_id \
+         WHERE \"p\".\"status\" = ? ORDER BY \"p\".\"created\" DESC LIMIT ? OFFSET ?"
+    );
+    assert_eq!(select.to_values().unwrap(), query.to_values());
+
+    assert_eq!(
+        select.to_count_sql().unwrap(),
+        "SELECT COUNT(*) FROM \"users\" AS \"u\" \
+         LEFT JOIN \"posts\" AS \"p\" ON p.user_id = u.id \
+         INNER JOIN \"teams\" ON teams.id = u.team_id \
+         WHERE \"p\".\"status\" = ?"
+    );
+    assert_eq!(select.count_values().unwrap(), query.count_values());
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_select_fields() {
+    let mut query = Query::new();
+    assert_eq!(query.renderer().column_list().unwrap(), None);
+
+    query.fields.add("id".to_string()).add("u.name".to_string());
+    assert_eq!(
+        query.select("users").to_sql().unwrap(),
+        "SELECT \"id\", \"u\".\"name\" FROM \"users\" LIMIT ? OFFSET ?"
+    );
+
+    // Explicit columns take precedence over the query's fields
+    assert_eq!(
+        query.select("users").column("email").to_sql().unwrap(),
+        "SELECT \"email\" FROM \"users\" LIMIT ? OFFSET ?"
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_select_invalid_cursor() {
+    let mut query = Query::new();
+    query.order.ascending("id".to_string());
+    query.cursor = Some(Cursor::new(vec!["1".to_string(), "2".to_string()]));
+
+    let select = query.select("users");
+    assert!(matches!(
+        select.to_sql(),
+        Err(error::Error::InvalidCursor(_))
+    ));
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_query_select_with_sqlite() {
+    let connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE users (id INTEGER, name TEXT);
+             CREATE TABLE posts (user_id INTEGER, title TEXT);
+             INSERT INTO users VALUES (1, 'john'), (2, 'jane'), (3, 'bob');
+             INSERT INTO posts VALUES (1, 'hello'), (1, 'again'), (2, 'hi');",
+        )
+        .unwrap();
+
+    let mut query = Query::new();
+    query
+        .parameters
+        .starts_with("u.name".to_string(), vec!["j".to_string()]);
+    query.order.ascending("p.title".to_string());
+
+    let select = query
+        .select("users")
+        .alias("u")
+        .columns(&["u.name", "p.title"])
+        .join(sql::Join::inner("posts", "p.user_id = u.id").alias("p"));
+
+    let mut statement = connection.prepare(&select.to_sql().unwrap()).unwrap();
+    let rows: Vec<(String, String)> = statement
+        .query_map(
+            rusqlite::params_from_iter(select.to_values().unwrap()),
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            ("john".to_string(), "again".to_string()),
+            ("john".to_string(), "hello".to_string()),
+            ("jane".to_string(), "hi".to_string()),
+        ]
+    );
+
+    let total: i64 = connection
+        .query_row(
+            &select.to_count_sql().unwrap(),
+            rusqlite::params_from_iter(select.count_values().unwrap()),
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(total, 3);
+}
+
+#[cfg(feature = "rusqlite")]
+fn people() -> rusqlite::Connection {
+    let connection = rusqlite::Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE people (id INTEGER, name TEXT, age INTEGER);
+             INSERT INTO people VALUES
+                (1, 'ann', 30), (2, 'bob', 17), (3, 'cat', 40),
+                (4, 'dan', 25), (5, 'eve', 35), (6, 'fay', 15);",
+        )
+        .unwrap();
+    connection
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_query_fetch() {
+    let connection = people();
+
+    let mut query = Query::new();
+    query
+        .parameters
+        .greater_or_equal("age".to_string(), vec!["18".to_string()]);
+    query.order.descending("age".to_string());
+    query.limit = 3;
+
+    let names = query
+        .fetch(&connection, "people", |row| row.get::<_, String>("name"))
+        .unwrap();
+    assert_eq!(names, vec!["cat", "eve", "ann"]);
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_query_fetch_surfaces_sqlite_errors() {
+    let connection = people();
+    let query = Query::new();
+
+    let result = query.fetch(&connection, "missing", |row| row.get::<_, i64>(0));
+    assert!(matches!(result, Err(error::Error::Sqlite(_))));
+
+    let result = query.fetch(&connection, "people", |row| row.get::<_, i64>("nope"));
+    assert!(matches!(result, Err(error::Error::Sqlite(_))));
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_query_fetch_page_with_offset() {
+    let connection = people();
+
+    let mut query = Query::new();
+    query
+        .parameters
+        .greater_or_equal("age".to_string(), vec!["18".to_string()]);
+    query.order.ascending("id".to_string());
+    query.limit = 2;
+
+    let page = query
+        .fetch_page(&connection, "people", |row| row.get::<_, i64>("id"))
+        .unwrap();
+    assert_eq!(page.rows, vec![1, 3]);
+    assert_eq!(page.total, 4);
+    assert!(page.has_next());
+
+    let next = page.next.unwrap();
+    assert_eq!(next.offset, 2);
+    assert_eq!(next.limit, 2);
+
+    let page = next
+        .fetch_page(&connection, "people", |row| row.get::<_, i64>("id"))
+        .unwrap();
+    assert_eq!(page.rows, vec![4, 5]);
+    assert_eq!(page.total, 4);
+    // Exactly full last page: the extra row fetched tells there is no more
+    assert_eq!(page.next, None);
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_query_fetch_page_with_cursor() {
+    let connection = people();
+
+    let mut query = Query::new();
+    query
+        .order
+        .descending("age".to_string())
+        .ascending("id".to_string());
+    query.limit = 4;
+    query.cursor = Some(Cursor::new(vec!["99".to_string(), "0".to_string()]));
+
+    let page = query
+        .fetch_page(&connection, "people", |row| row.get::<_, i64>("id"))
+        .unwrap();
+    assert_eq!(page.rows, vec![3, 5, 1, 4]);
+    assert_eq!(page.total, 6);
+
+    let next = page.next.unwrap();
+    assert_eq!(
+        next.cursor,
+        Some(Cursor::new(vec!["25".to_string(), "4".to_string()]))
+    );
+    assert_eq!(next.offset, 0);
+
+    let page = next
+        .fetch_page(&connection, "people", |row| row.get::<_, i64>("id"))
+        .unwrap();
+    assert_eq!(page.rows, vec![2, 6]);
+    assert_eq!(page.next, None);
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_select_fetch_page_with_projection() {
+    let connection = people();
+
+    let mut query = Query::new();
+    query.fields.add("id".to_string()).add("name".to_string());
+    query.order.ascending("id".to_string());
+    query.limit = 5;
+    query.cursor = Some(Cursor::new(vec!["0".to_string()]));
+
+    let page = query
+        .select("people")
+        .fetch_page(&connection, |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        })
+        .unwrap();
+    assert_eq!(page.rows.len(), 5);
+    assert_eq!(page.rows[4], (5, "eve".to_string()));
+    assert_eq!(
+        page.next.unwrap().cursor,
+        Some(Cursor::new(vec!["5".to_string()]))
+    );
+
+    // The order field must be selected to build the next cursor
+    query.fields = "name".parse().unwrap();
+    let result = query
+        .select("people")
+        .fetch_page(&connection, |row| row.get::<_, String>(0));
+    assert!(matches!(result, Err(error::Error::Sqlite(_))));
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_update_to_sql() {
+    let mut parameters = Parameters::new();
+    parameters
+        .equals("status".to_string(), vec!["draft".to_string()])
+        .lesser("created".to_string(), vec!["2025-01-01".to_string()]);
+    let query = Query::from(parameters);
+
+    let update = query
+        .update("posts")
+        .set("status", sql::Value::Text("archived".to_string()))
+        .set("archived", sql::Value::Integer(1));
+    assert_eq!(
+        update.to_sql().unwrap(),
+        "UPDATE \"posts\" SET \"status\" = ?, \"archived\" = ? WHERE \"status\" = ? AND \"created\" < ?"
+    );
+    assert_eq!(
+        update.to_values().unwrap(),
+        vec![
+            sql::Value::Text("archived".to_string()),
+            sql::Value::Integer(1),
+            sql::Value::Text("draft".to_string()),
+            sql::Value::Text("2025-01-01".to_string()),
+        ]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_update_ignores_order_pagination_and_cursor() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("status".to_string(), vec!["draft".to_string()]);
+    query.order.ascending("id".to_string());
+    query.limit = 10;
+    query.offset = 20;
+    query.cursor = Some(Cursor::new(vec!["5".to_string()]));
+
+    let update = query.update("posts").set("status", sql::Value::Null);
+    assert_eq!(
+        update.to_sql().unwrap(),
+        "UPDATE \"posts\" SET \"status\" = ? WHERE \"status\" = ?"
+    );
+    assert_eq!(update.to_values().unwrap().len(), 2);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_update_refuses_empty_where() {
+    let query = Query::new();
+    let update = query.update("posts").set("status", sql::Value::Null);
+    assert_eq!(
+        update.to_sql(),
+        Err(error::Error::MissingFilter("posts".to_string()))
+    );
+    assert_eq!(
+        update.to_values(),
+        Err(error::Error::MissingFilter("posts".to_string()))
+    );
+
+    // Conditions without values do not count as a filter
+    let mut query = Query::new();
+    query.parameters.equals("status".to_string(), vec![]);
+    assert!(
+        query
+            .update("posts")
+            .set("a", sql::Value::Null)
+            .to_sql()
+            .is_err()
+    );
+
+    assert_eq!(
+        Query::new()
+            .update("posts")
+            .set("status", sql::Value::Null)
+            .allow_unfiltered(true)
+            .to_sql()
+            .unwrap(),
+        "UPDATE \"posts\" SET \"status\" = ?"
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_update_resolves_assignments_through_schema() {
+    let mut schema = Schema::new();
+    schema
+        .field(Field::new("createdAt".to_string()).column("date_created".to_string()))
+        .field(Field::new("status".to_string()))
+        .field(Field::new("secret".to_string()).filterable(false));
+
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("createdAt".to_string(), vec!["2025-01-01".to_string()]);
+    let renderer = || query.renderer().schema(&schema);
+
+    // Assignments use the same columns as the WHERE clause
+    assert_eq!(
+        renderer()
+            .update("posts")
+            .set("createdAt", sql::Value::Null)
+            .set("status", sql::Value::Null)
+            .to_sql()
+            .unwrap(),
+        "UPDATE \"posts\" SET \"date_created\" = ?, \"status\" = ? WHERE \"date_created\" = ?"
+    );
+    for field in ["date_created", "secret"] {
+        assert_eq!(
+            renderer()
+                .update("posts")
+                .set(field, sql::Value::Null)
+                .to_sql(),
+            Err(error::Error::UnknownField(field.to_string()))
+        );
+    }
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_update_without_assignments() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("id".to_string(), vec!["1".to_string()]);
+    assert_eq!(
+        query.update("posts").to_sql(),
+        Err(error::Error::MissingAssignments("posts".to_string()))
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_delete_to_sql() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .is_not_null("deleted".to_string())
+        .contains("title".to_string(), vec!["spam".to_string()]);
+
+    let delete = query.delete("posts");
+    assert_eq!(
+        delete.to_sql().unwrap(),
+        "DELETE FROM \"posts\" WHERE \"deleted\" IS NOT NULL AND \"title\" LIKE ? ESCAPE '\\'"
+    );
+    assert_eq!(
+        delete.to_values().unwrap(),
+        vec![sql::Value::Text("%spam%".to_string())]
+    );
+
+    assert_eq!(
+        Query::new().delete("posts").to_sql(),
+        Err(error::Error::MissingFilter("posts".to_string()))
+    );
+    assert_eq!(
+        Query::new()
+            .delete("posts")
+            .allow_unfiltered(true)
+            .to_sql()
+            .unwrap(),
+        "DELETE FROM \"posts\""
+    );
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_update_and_delete_with_sqlite() {
+    let connection = people();
+
+    let query = Query::from("age=lesser:18".parse::<Parameters>().unwrap());
+    let updated = query
+        .update("people")
+        .set("name", sql::Value::Text("minor".to_string()))
+        .execute(&connection)
+        .unwrap();
+    assert_eq!(updated, 2);
+
+    let mut query = Query::new();
+    query
+        .parameters
+        .equals("name".to_string(), vec!["minor".to_string()]);
+    let deleted = query.delete("people").execute(&connection).unwrap();
+    assert_eq!(deleted, 2);
+
+    let remaining: i64 = connection
+        .query_row("SELECT COUNT(*) FROM people", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(remaining, 4);
+
+    // Refused before anything reaches the database
+    assert_eq!(
+        Query::new().delete("people").execute(&connection),
+        Err(error::Error::MissingFilter("people".to_string()))
+    );
+}
+
+#[cfg(feature = "sql")]
+fn condition(key: &str, similarity: Similarity, values: &[&str]) -> Filter {
+    Filter::Condition(
+        key.to_string(),
+        Parameter::init(similarity, values.iter().map(|v| v.to_string()).collect()),
+    )
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_with_or_group() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .contains("title".to_string(), vec!["rust".to_string()])
+        .group(Filter::Or(vec![
+            condition("status", Similarity::Equals, &["draft"]),
+            condition("owner", Similarity::Equals, &["me"]),
+        ]));
+
+    assert_eq!(
+        query.to_sql(),
+        "WHERE \"title\" LIKE ? ESCAPE '\\' AND (\"status\" = ? OR \"owner\" = ?) LIMIT ? OFFSET ?"
+    );
+    assert_eq!(
+        query.parameter_values(),
+        vec![
+            sql::Value::Text("%rust%".to_string()),
+            sql::Value::Text("draft".to_string()),
+            sql::Value::Text("me".to_string()),
+        ]
+    );
+    assert_eq!(query.total_parameters(), 5);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_with_nested_groups() {
+    let mut query = Query::new();
+    query.parameters.group(Filter::Or(vec![
+        Filter::And(vec![
+            condition("status", Similarity::Equals, &["draft", "review"]),
+            condition("age", Similarity::Between, &["1", "5"]),
+        ]),
+        Filter::Not(Box::new(Filter::Or(vec![
+            condition("owner", Similarity::Equals, &["me"]),
+            condition("name", Similarity::StartsWith, &["a", "b"]),
+        ]))),
+    ]));
+
+    assert_eq!(
+        query.where_clause(),
+        Some(
+            "(\"status\" IN (?, ?) AND \"age\" BETWEEN ? AND ?) OR NOT (\"owner\" = ? OR (\"name\" LIKE ? ESCAPE '\\' OR \"name\" LIKE ? ESCAPE '\\'))"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        query.parameter_values(),
+        vec![
+            sql::Value::Text("draft".to_string()),
+            sql::Value::Text("review".to_string()),
+            sql::Value::Integer(1),
+            sql::Value::Integer(5),
+            sql::Value::Text("me".to_string()),
+            sql::Value::Text("a%".to_string()),
+            sql::Value::Text("b%".to_string()),
+        ]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_groups_combined_with_and() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .group(Filter::Or(vec![
+            condition("a", Similarity::Equals, &["1"]),
+            condition("b", Similarity::Equals, &["2"]),
+        ]))
+        .group(Filter::Not(Box::new(condition(
+            "c",
+            Similarity::Greater,
+            &["3"],
+        ))));
+
+    assert_eq!(
+        query.where_clause(),
+        Some("(\"a\" = ? OR \"b\" = ?) AND NOT (\"c\" > ?)".to_string())
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_group_single_item_and_empty() {
+    let mut query = Query::new();
+    query
+        .parameters
+        .group(Filter::Or(vec![condition("a", Similarity::Equals, &["1"])]))
+        .group(Filter::Or(vec![]))
+        .group(Filter::And(vec![condition("b", Similarity::Equals, &[])]));
+
+    assert_eq!(query.where_clause(), Some("\"a\" = ?".to_string()));
+    assert_eq!(query.parameter_values(), vec![sql::Value::Integer(1)]);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_sql_group_several_conditions_on_a_field() {
+    let mut query = Query::new();
+    query.parameters.group(Filter::Or(vec![
+        Filter::And(vec![
+            condition("age", Similarity::Greater, &["18"]),
+            condition("age", Similarity::Lesser, &["65"]),
+        ]),
+        condition("vip", Similarity::Equals, &["1"]),
+    ]));
+
+    assert_eq!(
+        query.where_clause(),
+        Some("(\"age\" > ? AND \"age\" < ?) OR \"vip\" = ?".to_string())
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_values_between_odd_value_not_bound() {
+    let mut query = Query::new();
+    query.parameters.between(
+        "age".to_string(),
+        vec!["10".to_string(), "20".to_string(), "30".to_string()],
+    );
+
+    assert_eq!(
+        query.where_clause(),
+        Some("\"age\" BETWEEN ? AND ?".to_string())
+    );
+    assert_eq!(
+        query.parameter_values(),
+        vec![sql::Value::Integer(10), sql::Value::Integer(20)]
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_values_parameter_order_preservation() {
+    let mut query = Query::new();
+    query.parameters.inner_mut().insert(
+        "first".to_string(),
+        vec![Parameter::init(Similarity::Equals, vec!["1".to_string()])],
+    );
+    query.parameters.inner_mut().insert(
+        "second".to_string(),
+        vec![Parameter::init(Similarity::Contains, vec!["2".to_string()])],
+    );
+    query.parameters.inner_mut().insert(
+        "third".to_string(),
+        vec![Parameter::init(
+            Similarity::Between,
+            vec!["3".to_string(), "4".to_string()],
+        )],
+    );
+
+    let values = query.to_values();
+
+    assert_eq!(values.len(), 6);
+    assert_eq!(values[0], sql::Value::Integer(1));
+    assert_eq!(values[1], sql::Value::Text("%2%".to_string()));
+    assert_eq!(values[2], sql::Value::Integer(3));
+    assert_eq!(values[3], sql::Value::Integer(4));
+    assert_eq!(values[4], sql::Value::Integer(50)); // limit
+    assert_eq!(values[5], sql::Value::Integer(0)); // offset
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_to_values_with_custom_limit_offset_complex() {
+    let mut query = Query::new();
+    query.limit = 100;
+    query.offset = 25;
+
+    query.parameters.inner_mut().insert(
+        "name".to_string(),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["john".to_string()],
+        )],
+    );
+    query.parameters.inner_mut().insert(
+        "age".to_string(),
+        vec![Parameter::init(
+            Similarity::Between,
+            vec!["20".to_string(), "30".to_string()],
+        )],
+    );
+
+    let values = query.to_values();
+
+    assert_eq!(values.len(), 5);
+    assert_eq!(values[0], sql::Value::Text("%john%".to_string()));
+    assert_eq!(values[1], sql::Value::Integer(20));
+    assert_eq!(values[2], sql::Value::Integer(30));
+    assert_eq!(values[3], sql::Value::Integer(100)); // custom limit
+    assert_eq!(values[4], sql::Value::Integer(25)); // custom offset
+}
+
+#[cfg(feature = "sql")]
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_sql_value_enum_variants() {
+    // Test all SQLValue enum variants
+    let null = sql::Value::Null;
+    let integer = sql::Value::Integer(42);
+    let real = sql::Value::Real(3.14);
+    let text = sql::Value::Text("hello".to_string());
+    let blob = sql::Value::Blob(vec![1, 2, 3, 4]);
+
+    assert_eq!(null, sql::Value::Null);
+    assert_eq!(integer, sql::Value::Integer(42));
+    assert_eq!(real, sql::Value::Real(3.14));
+    assert_eq!(text, sql::Value::Text("hello".to_string()));
+    assert_eq!(blob, sql::Value::Blob(vec![1, 2, 3, 4]));
+}
+
+#[cfg(feature = "sql")]
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_sql_value_enum_derived_traits() {
+    // Test Clone
+    let original = sql::Value::Text("test".to_string());
+    let cloned = original.clone();
+    assert_eq!(original, cloned);
+
+    // Test Debug
+    let debug_str = format!("{:?}", sql::Value::Integer(42));
+    assert!(debug_str.contains("Integer"));
+    assert!(debug_str.contains("42"));
+
+    // Test PartialEq
+    assert_eq!(sql::Value::Null, sql::Value::Null);
+    assert_eq!(sql::Value::Integer(42), sql::Value::Integer(42));
+    assert_eq!(sql::Value::Real(3.14), sql::Value::Real(3.14));
+    assert_eq!(
+        sql::Value::Text("hello".to_string()),
+        sql::Value::Text("hello".to_string())
+    );
+    assert_eq!(
+        sql::Value::Blob(vec![1, 2, 3]),
+        sql::Value::Blob(vec![1, 2, 3])
+    );
+
+    // Test inequality
+    assert_ne!(sql::Value::Null, sql::Value::Integer(0));
+    assert_ne!(sql::Value::Integer(42), sql::Value::Integer(43));
+    assert_ne!(sql::Value::Real(3.14), sql::Value::Real(3.15));
+    assert_ne!(
+        sql::Value::Text("hello".to_string()),
+        sql::Value::Text("world".to_string())
+    );
+    assert_ne!(
+        sql::Value::Blob(vec![1, 2, 3]),
+        sql::Value::Blob(vec![1, 2, 4])
+    );
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_parameter_values() {
+    let mut query = Query::new();
+    query.parameters.inner_mut().insert(
+        "name".to_string(),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["john".to_string()],
+        )],
+    );
+    query.parameters.inner_mut().insert(
+        "age".to_string(),
+        vec![Parameter::init(Similarity::Equals, vec!["25".to_string()])],
+    );
+
+    let values = query.parameter_values();
+
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0], sql::Value::Text("%john%".to_string()));
+    assert_eq!(values[1], sql::Value::Integer(25));
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_parameter_values_empty() {
+    let query = Query::new();
+    let values = query.parameter_values();
+
+    assert_eq!(values.len(), 0);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_pagination_values() {
+    let mut query = Query::new();
+    query.limit = 100;
+    query.offset = 25;
+
+    let values = query.pagination_values();
+
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0], sql::Value::Integer(100));
+    assert_eq!(values[1], sql::Value::Integer(25));
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_total_parameters() {
+    let mut query = Query::new();
+    query.parameters.inner_mut().insert(
+        "name".to_string(),
+        vec![Parameter::init(
+            Similarity::Contains,
+            vec!["john".to_string(), "jane".to_string()],
+        )],
+    );
+    query.parameters.inner_mut().insert(
+        "age".to_string(),
+        vec![Parameter::init(Similarity::Equals, vec!["25".to_string()])],
+    );
+
+    let count = query.total_parameters();
+
+    // 2 name values + 1 age value + 2 pagination = 5 total
+    assert_eq!(count, 5);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_total_parameters_empty() {
+    let query = Query::new();
+    let count = query.total_parameters();
+
+    // Only pagination values
+    assert_eq!(count, 2);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_total_parameters_with_empty_parameters() {
+    let mut query = Query::new();
+    query.parameters.inner_mut().insert(
+        "empty".to_string(),
+        vec![Parameter::init(Similarity::Equals, vec![])],
+    );
 
     let count = query.total_parameters();
 
@@ -1712,7 +3249,7 @@ fn test_query_to_values_ignores_empty_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Contains,
             vec![
                 "john".to_string(),
@@ -1721,18 +3258,18 @@ fn test_query_to_values_ignores_empty_values() {
                 "   ".to_string(), // whitespace only
                 "bob".to_string(),
             ],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec![
                 "25".to_string(),
                 "".to_string(), // empty string
                 "30".to_string(),
             ],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1754,7 +3291,7 @@ fn test_query_parameter_values_ignores_empty_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Contains,
             vec![
                 "john".to_string(),
@@ -1762,7 +3299,7 @@ fn test_query_parameter_values_ignores_empty_values() {
                 "jane".to_string(),
                 "   ".to_string(), // whitespace only
             ],
-        ),
+        )],
     );
 
     let values = query.parameter_values();
@@ -1779,7 +3316,7 @@ fn test_query_total_parameters_ignores_empty_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Contains,
             vec![
                 "john".to_string(),
@@ -1787,17 +3324,17 @@ fn test_query_total_parameters_ignores_empty_values() {
                 "jane".to_string(),
                 "   ".to_string(), // whitespace only
             ],
-        ),
+        )],
     );
     query.parameters.inner_mut().insert(
         "age".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec![
                 "25".to_string(),
                 "".to_string(), // empty string
             ],
-        ),
+        )],
     );
 
     let count = query.total_parameters();
@@ -1812,7 +3349,7 @@ fn test_query_to_values_all_empty_values() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "name".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Contains,
             vec![
                 "".to_string(),    // empty string
@@ -1820,7 +3357,7 @@ fn test_query_to_values_all_empty_values() {
                 "\t".to_string(),  // tab only
                 "\n".to_string(),  // newline only
             ],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1837,7 +3374,7 @@ fn test_query_to_values_mixed_empty_and_null() {
     let mut query = Query::new();
     query.parameters.inner_mut().insert(
         "status".to_string(),
-        Parameter::init(
+        vec![Parameter::init(
             Similarity::Equals,
             vec![
                 "".to_string(),        // empty string (should be ignored)
@@ -1845,7 +3382,7 @@ fn test_query_to_values_mixed_empty_and_null() {
                 "   ".to_string(),     // whitespace only (should be ignored)
                 "active".to_string(),  // normal value
             ],
-        ),
+        )],
     );
 
     let values = query.to_values();
@@ -1860,14 +3397,14 @@ fn test_query_to_values_mixed_empty_and_null() {
 
 #[cfg(feature = "sql")]
 use query_lite::sql;
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 use rusqlite::types::ToSql;
 
 // ============================================================================
 // RUSQLITE ToSql TESTS
 // ============================================================================
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_null() {
     let value = sql::Value::Null;
@@ -1879,7 +3416,7 @@ fn test_value_tosql_null() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_integer() {
     let value = sql::Value::Integer(42);
@@ -1893,7 +3430,7 @@ fn test_value_tosql_integer() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_integer_negative() {
     let value = sql::Value::Integer(-123);
@@ -1907,7 +3444,7 @@ fn test_value_tosql_integer_negative() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_integer_large() {
     let value = sql::Value::Integer(9223372036854775807i64);
@@ -1921,8 +3458,9 @@ fn test_value_tosql_integer_large() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_value_tosql_real() {
     let value = sql::Value::Real(3.14);
     let to_sql_output = value.to_sql().unwrap();
@@ -1935,7 +3473,7 @@ fn test_value_tosql_real() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_real_negative() {
     let value = sql::Value::Real(-123.456);
@@ -1949,7 +3487,7 @@ fn test_value_tosql_real_negative() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_text() {
     let value = sql::Value::Text("hello world".to_string());
@@ -1963,7 +3501,7 @@ fn test_value_tosql_text() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_text_empty() {
     let value = sql::Value::Text("".to_string());
@@ -1977,7 +3515,7 @@ fn test_value_tosql_text_empty() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_text_unicode() {
     let value = sql::Value::Text("测试".to_string());
@@ -1991,7 +3529,7 @@ fn test_value_tosql_text_unicode() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_blob() {
     let value = sql::Value::Blob(vec![1, 2, 3, 4, 5]);
@@ -2005,7 +3543,7 @@ fn test_value_tosql_blob() {
     }
 }
 
-#[cfg(feature = "sql")]
+#[cfg(feature = "rusqlite")]
 #[test]
 fn test_value_tosql_blob_empty() {
     let value = sql::Value::Blob(vec![]);
@@ -2013,8 +3551,70 @@ fn test_value_tosql_blob_empty() {
 
     match to_sql_output {
         rusqlite::types::ToSqlOutput::Borrowed(rusqlite::types::ValueRef::Blob(bytes)) => {
-            assert_eq!(bytes, &[]);
+            assert!(bytes.is_empty());
         }
         _ => panic!("Expected Blob ValueRef"),
     }
 }
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_placeholders_embed_in_handwritten_sql() {
+    let connection = people();
+    let query = Query::from("age=greater-or-equal:18".parse::<Parameters>().unwrap());
+
+    // Numbered after the statement's own ?1
+    let renderer = query
+        .renderer()
+        .placeholders(sql::Placeholders::Numbered)
+        .placeholder_offset(1);
+    let sql = format!(
+        "SELECT name FROM people WHERE name <> ?1 AND {} ORDER BY id",
+        renderer.where_clause().unwrap().unwrap()
+    );
+    let mut values = vec![sql::Value::Text("ann".to_string())];
+    values.extend(renderer.parameter_values().unwrap());
+    let names: Vec<String> = connection
+        .prepare(&sql)
+        .unwrap()
+        .query_map(rusqlite::params_from_iter(values), |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(names, vec!["cat", "dan", "eve"]);
+
+    // Named, bound next to the statement's own :skip
+    let renderer = query.renderer().placeholders(sql::Placeholders::Named);
+    let sql = format!(
+        "SELECT name FROM people WHERE name <> :skip AND {} ORDER BY id",
+        renderer.where_clause().unwrap().unwrap()
+    );
+    let skip = sql::Value::Text("cat".to_string());
+    let named = renderer.named_values(renderer.parameter_values().unwrap());
+    let mut params: Vec<(&str, &dyn sql::ToSql)> = vec![(":skip", &skip)];
+    params.extend(
+        named
+            .iter()
+            .map(|(name, value)| (name.as_str(), value as &dyn sql::ToSql)),
+    );
+    let names: Vec<String> = connection
+        .prepare(&sql)
+        .unwrap()
+        .query_map(params.as_slice(), |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(names, vec!["ann", "dan", "eve"]);
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_query_pagination_values_saturate() {
+    let mut query = Query::new();
+    query.limit = usize::MAX;
+    query.offset = usize::MAX;
+    assert_eq!(
+        query.renderer().pagination_values(),
+        vec![sql::Value::Integer(i64::MAX), sql::Value::Integer(i64::MAX)]
+    );
+}
@@ -0,0 +1,158 @@
+#![cfg(feature = "sqlx")]
+
+use futures_executor::block_on;
+use query_lite::*;
+use sqlx_core::connection::Connection;
+use sqlx_core::query::{query, query_with};
+use sqlx_core::query_builder::QueryBuilder;
+use sqlx_core::row::Row;
+use sqlx_core::sql_str::AssertSqlSafe;
+use sqlx_sqlite::{Sqlite, SqliteConnection};
+
+fn people() -> SqliteConnection {
+    block_on(async {
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        query("CREATE TABLE people (id INTEGER, name TEXT, age INTEGER, score REAL, avatar BLOB)")
+            .execute(&mut connection)
+            .await
+            .unwrap();
+        query(
+            "INSERT INTO people VALUES
+                (1, 'ann', 30, 1.5, x'01'), (2, 'bob', 17, NULL, NULL),
+                (3, 'cat', 40, 2.5, x'0203'), (4, 'dan', 25, 0.5, NULL)",
+        )
+        .execute(&mut connection)
+        .await
+        .unwrap();
+        connection
+    })
+}
+
+// ============================================================================
+// SQLX ENCODE TESTS
+// ============================================================================
+
+#[test]
+fn test_sqlx_bind_values() {
+    let mut connection = people();
+
+    let mut q = Query::new();
+    q.parameters
+        .greater_or_equal("age".to_string(), vec!["18".to_string()])
+        .starts_with("name".to_string(), vec!["a".to_string(), "c".to_string()]);
+    q.order.descending("age".to_string());
+
+    let sql = format!("SELECT id FROM people {}", q.to_sql());
+    let statement = q
+        .to_values()
+        .into_iter()
+        .fold(query::<Sqlite>(AssertSqlSafe(sql)), |statement, value| {
+            statement.bind(value)
+        });
+
+    let ids: Vec<i64> = block_on(statement.fetch_all(&mut connection))
+        .unwrap()
+        .iter()
+        .map(|row| row.get("id"))
+        .collect();
+    assert_eq!(ids, vec![3, 1]);
+}
+
+#[test]
+fn test_sqlx_arguments() {
+    let mut connection = people();
+
+    let mut q = Query::new();
+    q.parameters
+        .is_null("avatar".to_string())
+        .lesser("score".to_string(), vec!["1".to_string()]);
+
+    let select = q.select("people").column("name");
+    let statement = query_with(
+        AssertSqlSafe(select.to_sql().unwrap()),
+        sql::sqlx_arguments(select.to_values().unwrap()).unwrap(),
+    );
+
+    let names: Vec<String> = block_on(statement.fetch_all(&mut connection))
+        .unwrap()
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(names, vec!["dan"]);
+}
+
+#[test]
+fn test_sqlx_query_builder() {
+    let mut connection = people();
+
+    let mut builder = QueryBuilder::<Sqlite>::new("SELECT name FROM people WHERE id IN (");
+    let mut separated = builder.separated(", ");
+    for value in [sql::Value::Integer(2), sql::Value::Integer(4)] {
+        separated.push_bind(value);
+    }
+    builder.push(") ORDER BY id");
+
+    let names: Vec<String> = block_on(builder.build().fetch_all(&mut connection))
+        .unwrap()
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(names, vec!["bob", "dan"]);
+}
+
+#[test]
+fn test_sqlx_encode_every_variant() {
+    let mut connection = people();
+
+    let values = vec![
+        sql::Value::Null,
+        sql::Value::Integer(42),
+        sql::Value::Real(2.75),
+        sql::Value::Text("测试".to_string()),
+        sql::Value::Blob(vec![1, 2, 3]),
+    ];
+    let statement = query_with(
+        "SELECT typeof(?), ?, ?, ?, ?",
+        sql::sqlx_arguments(values).unwrap(),
+    );
+
+    let row = block_on(statement.fetch_one(&mut connection)).unwrap();
+    assert_eq!(row.get::<String, _>(0), "null");
+    assert_eq!(row.get::<i64, _>(1), 42);
+    assert_eq!(row.get::<f64, _>(2), 2.75);
+    assert_eq!(row.get::<String, _>(3), "测试");
+    assert_eq!(row.get::<Vec<u8>, _>(4), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_sqlx_update_and_delete() {
+    let mut connection = people();
+
+    let mut q = Query::new();
+    q.parameters
+        .lesser("age".to_string(), vec!["18".to_string()]);
+
+    let update = q
+        .update("people")
+        .set("name", sql::Value::Text("minor".to_string()));
+    let result = block_on(
+        query_with(
+            AssertSqlSafe(update.to_sql().unwrap()),
+            sql::sqlx_arguments(update.to_values().unwrap()).unwrap(),
+        )
+        .execute(&mut connection),
+    )
+    .unwrap();
+    assert_eq!(result.rows_affected(), 1);
+
+    let delete = q.delete("people");
+    let result = block_on(
+        query_with(
+            AssertSqlSafe(delete.to_sql().unwrap()),
+            sql::sqlx_arguments(delete.to_values().unwrap()).unwrap(),
+        )
+        .execute(&mut connection),
+    )
+    .unwrap();
+    assert_eq!(result.rows_affected(), 1);
+}
@@ -0,0 +1,367 @@
+#![cfg(feature = "http")]
+
+use query_lite::error::Error;
+use query_lite::syntax::{Aliases, Django, JsonApi, Native, Rails, Syntax};
+use query_lite::*;
+
+// ============================================================================
+// HTTP SYNTAX TESTS
+// ============================================================================
+
+fn json_api(search: &str) -> Query {
+    Query::from_http_with_syntax(search.to_string(), &JsonApi::new()).unwrap()
+}
+
+fn django(search: &str) -> Query {
+    Query::from_http_with_syntax(search.to_string(), &Django::new()).unwrap()
+}
+
+fn rails(search: &str) -> Query {
+    Query::from_http_with_syntax(search.to_string(), &Rails::new()).unwrap()
+}
+
+fn native(search: &str) -> Query {
+    Query::from_http(search.to_string()).unwrap()
+}
+
+#[test]
+fn test_syntax_native() {
+    let search = "name=contains:jo&age=greater:18&order=created:desc&limit=20&offset=40";
+    let config = ParseConfig::default();
+    assert_eq!(Native.parse(search, &config).unwrap(), native(search));
+    assert_eq!(
+        native(search).to_http_with_syntax(&Native),
+        native(search).to_http()
+    );
+}
+
+#[test]
+fn test_syntax_json_api_parse() {
+    assert_eq!(
+        json_api("filter[name][contains]=jo&sort=-created&page[size]=20&page[number]=2"),
+        native("name=contains:jo&order=created:desc&limit=20&offset=20")
+    );
+    assert_eq!(
+        json_api("?filter[status]=active,pending&filter[age][greater]=18&filter[age][lesser]=65"),
+        native("status=equals:active,pending&age=greater:18&age=lesser:65")
+    );
+    assert_eq!(
+        json_api("filter[email][ends-with-ci]=@EXAMPLE.COM&filter[deleted][is-null]"),
+        native("email=ends-with-ci:@EXAMPLE.COM&deleted=is-null:")
+    );
+    assert_eq!(
+        json_api("filter[or]=(status=equals:draft,owner=equals:me)&sort=name,-id"),
+        native("or=(status=equals:draft,owner=equals:me)&order=name:asc,id:desc")
+    );
+}
+
+#[test]
+fn test_syntax_json_api_encoded_keys() {
+    assert_eq!(
+        json_api("filter%5Bname%5D%5Bcontains%5D=john%20doe&page%5Bsize%5D=5"),
+        native("name=contains:john%20doe&limit=5")
+    );
+}
+
+#[test]
+fn test_syntax_json_api_pages() {
+    // The page number may come before the size
+    let query = json_api("page[number]=3&page[size]=10");
+    assert_eq!((query.limit, query.offset), (10, 20));
+
+    let query = json_api("page[number]=2");
+    assert_eq!((query.limit, query.offset), (Parameters::DEFAULT_LIMIT, 50));
+
+    let query = json_api("page[size]=10&page[offset]=15");
+    assert_eq!((query.limit, query.offset), (10, 15));
+
+    let mut query = json_api("sort=-score,id&page[size]=10");
+    query.cursor = Some(Cursor::new(vec!["90".to_string(), "7".to_string()]));
+    let search = query.to_http_with_syntax(&JsonApi::new());
+    assert!(search.contains("page[cursor]="));
+    assert!(!search.contains("page[number]"));
+    assert_eq!(json_api(&search), query);
+}
+
+#[test]
+fn test_syntax_json_api_config() {
+    let config = ParseConfig::new().max_limit(100).max_offset(1000);
+    let parse = |search: &str, config: &ParseConfig| JsonApi::new().parse(search, config);
+
+    let query = parse("page[size]=500&page[number]=50", &config).unwrap();
+    assert_eq!((query.limit, query.offset), (100, 1000));
+
+    // Page 0 and malformed numbers fall back to the first page
+    assert_eq!(parse("page[number]=0", &config).unwrap().offset, 0);
+    assert_eq!(parse("page[number]=abc", &config).unwrap().offset, 0);
+
+    let strict = config.policy(Policy::Strict);
+    assert_eq!(
+        parse("page[size]=20&page[number]=100", &strict),
+        Err(Error::InvalidOffset("100".to_string()))
+    );
+    assert_eq!(
+        parse("page[number]=0", &strict),
+        Err(Error::InvalidOffset("0".to_string()))
+    );
+    assert_eq!(
+        parse("page[size]=500", &strict),
+        Err(Error::InvalidLimit("500".to_string()))
+    );
+}
+
+#[test]
+fn test_syntax_json_api_fields() {
+    let syntax = JsonApi::new().resource("articles".to_string());
+    let query = syntax
+        .parse(
+            "fields[articles]=title,body&fields[people]=name&include=author",
+            &ParseConfig::default(),
+        )
+        .unwrap();
+    assert_eq!(
+        query.fields.inner().iter().collect::<Vec<_>>(),
+        vec!["title", "body"]
+    );
+    assert!(
+        syntax
+            .render(&query)
+            .contains("fields[articles]=title,body")
+    );
+
+    assert_eq!(json_api("fields=id,name").fields.inner().len(), 2);
+}
+
+#[test]
+fn test_syntax_json_api_render() {
+    let query = native(
+        "name=contains:jo&status=equals:a,b&age=greater:18&age=lesser:65&order=created:desc,id:asc&limit=20&offset=40",
+    );
+    assert_eq!(
+        query.to_http_with_syntax(&JsonApi::new()),
+        "filter[name][contains]=jo&filter[status]=a,b&filter[age][greater]=18&filter[age][lesser]=65\
+         &sort=-created,id&page[size]=20&page[number]=3"
+    );
+
+    let query = native("limit=10&offset=15");
+    assert_eq!(
+        query.to_http_with_syntax(&JsonApi::new()),
+        "page[size]=10&page[offset]=15"
+    );
+}
+
+#[test]
+fn test_syntax_json_api_round_trip() {
+    let searches = [
+        "name=contains-ci:JO&deleted=is-null:&owner=not-equals:null,bob",
+        "age=greater:18&age=greater:21&order=score:desc,id:asc&limit=25&offset=50",
+        "or=(status=equals:draft,owner=equals:me)&or=(a=equals:1,b=equals:2)&not=(archived=equals:1)",
+        "title=contains:a%2Cb%20c&fields=id,title&limit=7&offset=3",
+        "created=between:2025-01-01T10:00,2025-01-01T18:00&limit=0",
+    ];
+    for search in searches {
+        let query = native(search);
+        let rendered = query.to_http_with_syntax(&JsonApi::new());
+        assert_eq!(json_api(&rendered), query, "{}", rendered);
+    }
+}
+
+#[test]
+fn test_syntax_json_api_errors() {
+    let parse = |search: &str| Query::from_http_with_syntax(search.to_string(), &JsonApi::new());
+
+    assert_eq!(
+        parse("filter[name][sort-of]=jo"),
+        Err(Error::InvalidSimilarity("sort-of".to_string()))
+    );
+    assert_eq!(
+        parse("filter[deleted][is-null]=x"),
+        Err(Error::InvalidParameter(
+            "filter[deleted][is-null]=x".to_string()
+        ))
+    );
+    assert_eq!(
+        parse("filter[a][b][c]=1"),
+        Err(Error::InvalidFilter("filter[a][b][c]=1".to_string()))
+    );
+    assert_eq!(
+        parse("filter[name=jo"),
+        Err(Error::InvalidSearchParameters("filter[name=jo".to_string()))
+    );
+    assert_eq!(
+        parse("page[limit]=10"),
+        Err(Error::InvalidSearchParameters("page[limit]=10".to_string()))
+    );
+    assert!(matches!(
+        parse("filter[or]=status=equals:draft"),
+        Err(Error::InvalidFilter(_))
+    ));
+    assert!(matches!(
+        parse("page[cursor]=!!"),
+        Err(Error::InvalidCursor(_))
+    ));
+    let token = Cursor::new(vec!["7".to_string()]).to_string();
+    assert_eq!(
+        parse(&format!("filter[tenant]=7&page[cursor]={token}")),
+        Err(Error::InvalidCursor(token))
+    );
+}
+
+#[test]
+fn test_syntax_django_parse() {
+    assert_eq!(
+        django("age__gte=18&name__icontains=jo&order=name:asc&limit=20"),
+        native("age=greater-or-equal:18&name=contains-ci:jo&order=name:asc&limit=20")
+    );
+    assert_eq!(
+        django(
+            "?status__in=active,pending&created__range=2025-01-01,2025-02-01&email__iendswith=@X.COM"
+        ),
+        native(
+            "status=equals:active,pending&created=between:2025-01-01,2025-02-01&email=ends-with-ci:@X.COM"
+        )
+    );
+    // Native names work too, and unknown suffixes are part of the field
+    assert_eq!(
+        django("age__not-between=1,5&author__name=jo,bob"),
+        native("age=not-between:1,5&author__name=equals:jo%2Cbob")
+    );
+    assert_eq!(
+        django("deleted__isnull=true&owner__isnull=false&or=(a=equals:1,b=equals:2)"),
+        native("deleted=is-null:&owner=is-not-null:&or=(a=equals:1,b=equals:2)")
+    );
+}
+
+#[test]
+fn test_syntax_rails_parse() {
+    assert_eq!(
+        rails("price[lt]=100&name[i_cont]=jo&status[not_in]=a,b"),
+        native("price=lesser:100&name=contains-ci:jo&status=not-equals:a,b")
+    );
+    assert_eq!(
+        rails("price%5Bgte%5D=10&deleted[null]=1&owner[not_null]&name=jo"),
+        native("price=greater-or-equal:10&deleted=is-null:&owner=is-not-null:&name=jo")
+    );
+}
+
+#[test]
+fn test_syntax_aliases() {
+    let aliases = Aliases::django()
+        .alias("ne".to_string(), Similarity::NotEquals, Case::Sensitive)
+        .remove("in");
+    assert_eq!(
+        aliases.get("ne"),
+        Some(&(Similarity::NotEquals, Case::Sensitive))
+    );
+    assert_eq!(
+        aliases.find(&Similarity::Equals, Case::Sensitive),
+        Some("exact")
+    );
+
+    let syntax = Django::new().aliases(aliases);
+    let query = syntax
+        .parse("status__ne=done&tags__exact=a,b", &ParseConfig::default())
+        .unwrap();
+    assert_eq!(query, native("status=not-equals:done&tags=equals:a,b"));
+    assert_eq!(
+        syntax.render(&query),
+        "status__ne=done&tags__exact=a,b&limit=50&offset=0"
+    );
+
+    // Without its alias, `in` is an ordinary field name suffix
+    assert_eq!(
+        syntax.parse("id__in=1", &ParseConfig::default()).unwrap(),
+        native("id__in=1")
+    );
+}
+
+#[test]
+fn test_syntax_keyed_render() {
+    let query = native(
+        "name=contains-ci:jo&age=greater-or-equal:18&status=equals:a,b&owner=bob&deleted=is-null:&verified=is-not-null:&order=id:desc&limit=10",
+    );
+    assert_eq!(
+        query.to_http_with_syntax(&Django::new()),
+        "name__icontains=jo&age__gte=18&status__in=a,b&owner=bob&deleted__isnull=true\
+         &verified__isnull=false&order=id:desc&limit=10&offset=0"
+    );
+    assert_eq!(
+        query.to_http_with_syntax(&Rails::new()),
+        "name[i_cont]=jo&age[gte]=18&status[in]=a,b&owner=bob&deleted[null]=true\
+         &verified[not_null]=true&order=id:desc&limit=10&offset=0"
+    );
+
+    // Similarities without an alias keep their native names
+    let query = native("name=like-ci:j%25&age=not-between:1,5");
+    assert_eq!(
+        query.to_http_with_syntax(&Django::new()),
+        "name__like-ci=j%25&age__not-between=1,5&limit=50&offset=0"
+    );
+}
+
+#[test]
+fn test_syntax_keyed_round_trip() {
+    let searches = [
+        "name=contains-ci:JO&deleted=is-null:&owner=not-equals:null,bob",
+        "age=greater:18&age=greater:21&order=score:desc,id:asc&limit=25&offset=50",
+        "or=(status=equals:draft,owner=equals:me)&not=(archived=equals:1)",
+        "title=contains:a%2Cb%20c&city=New%20York,%20NY&fields=id,title&limit=7&offset=3",
+        "created=between:2025-01-01T10:00,2025-01-01T18:00&email=equals-ci:A@X.COM",
+    ];
+    for search in searches {
+        let query = native(search);
+        let rendered = query.to_http_with_syntax(&Django::new());
+        assert_eq!(django(&rendered), query, "{}", rendered);
+        let rendered = query.to_http_with_syntax(&Rails::new());
+        assert_eq!(rails(&rendered), query, "{}", rendered);
+    }
+}
+
+#[test]
+fn test_syntax_keyed_errors() {
+    let parse_django =
+        |search: &str| Query::from_http_with_syntax(search.to_string(), &Django::new());
+    let parse_rails =
+        |search: &str| Query::from_http_with_syntax(search.to_string(), &Rails::new());
+
+    assert_eq!(
+        parse_rails("price[sort_of]=1"),
+        Err(Error::InvalidSimilarity("sort_of".to_string()))
+    );
+    assert_eq!(
+        parse_django("deleted__isnull=maybe"),
+        Err(Error::InvalidParameter("deleted__isnull=maybe".to_string()))
+    );
+    assert_eq!(
+        parse_rails("deleted[null]=x"),
+        Err(Error::InvalidParameter("deleted[null]=x".to_string()))
+    );
+    assert!(matches!(
+        parse_django("order=name"),
+        Err(Error::InvalidOrderField(_))
+    ));
+}
+
+#[test]
+fn test_syntax_keyed_encoded_keys() {
+    // Encoded `=`, `:` and `&` stay inside the field name
+    let query = rails("a%3Dequals%3Ab%26c[gt]=5");
+    assert_eq!(
+        query.parameters.inner().keys().collect::<Vec<_>>(),
+        vec!["a=equals:b&c"]
+    );
+    assert_eq!(
+        query.parameters.inner()["a=equals:b&c"][0],
+        Parameter::init(Similarity::Greater, vec!["5".to_string()])
+    );
+
+    let query = django("a%3Dequals%3Ab%26c=5&x%26limit%3D1__gte=2");
+    assert_eq!(
+        query.parameters.inner().keys().collect::<Vec<_>>(),
+        vec!["a=equals:b&c", "x&limit=1"]
+    );
+    assert_eq!(query.limit, Parameters::DEFAULT_LIMIT);
+
+    // Repeated plain keys mean `IN`, as in `from_http`
+    assert_eq!(django("name=john&name=doe"), native("name=john&name=doe"));
+}